@@ -0,0 +1,99 @@
+use crate::Arrow;
+use std::collections::BTreeMap;
+
+/// A named group of square highlights
+///
+/// Layers are drawn in the order of this enum, all between the board and the pieces;
+/// custom layers come last, sorted by name.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum HighlightLayer {
+    /// The origin and target squares of the previous move
+    LastMove,
+    /// Squares picked by the user
+    Selection,
+    /// The king in check
+    Check,
+    /// Any other marks
+    Custom(String),
+}
+
+/// How a highlighted square is marked
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HighlightStyle {
+    /// A translucent fill over the whole square
+    Fill,
+    /// A circle outlined inside the square
+    Ring,
+    /// Triangles in the four corners of the square
+    Corner,
+    /// A small disc in the center of the square
+    Dot,
+}
+
+/// A mark on one square
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Highlight {
+    pub x: isize,
+    pub y: isize,
+    pub style: HighlightStyle,
+    /// Overrides the color of the layer
+    pub color: Option<String>,
+}
+
+/// Highlights and arrows drawn over a board
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Annotations {
+    layers: BTreeMap<HighlightLayer, Vec<Highlight>>,
+    /// Arrows, drawn above the pieces
+    pub arrows: Vec<Arrow>,
+}
+
+impl Highlight {
+    pub fn new(x: isize, y: isize, style: HighlightStyle) -> Self {
+        Self { x, y, style, color: None }
+    }
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl Annotations {
+    /// Add a highlight to a layer
+    pub fn highlight(&mut self, layer: HighlightLayer, highlight: Highlight) -> &mut Self {
+        self.layers.entry(layer).or_default().push(highlight);
+        self
+    }
+    /// Mark the squares of the previous move, replacing the earlier one
+    pub fn last_move(&mut self, from: (isize, isize), to: (isize, isize)) -> &mut Self {
+        self.clear(&HighlightLayer::LastMove);
+        self.highlight(HighlightLayer::LastMove, Highlight::new(from.0, from.1, HighlightStyle::Fill));
+        self.highlight(HighlightLayer::LastMove, Highlight::new(to.0, to.1, HighlightStyle::Fill))
+    }
+    /// Mark a king in check
+    pub fn check(&mut self, x: isize, y: isize) -> &mut Self {
+        self.highlight(HighlightLayer::Check, Highlight::new(x, y, HighlightStyle::Ring))
+    }
+    /// Mark a selected square
+    pub fn select(&mut self, x: isize, y: isize) -> &mut Self {
+        self.highlight(HighlightLayer::Selection, Highlight::new(x, y, HighlightStyle::Fill))
+    }
+    /// Add an arrow
+    pub fn arrow(&mut self, arrow: Arrow) -> &mut Self {
+        self.arrows.push(arrow);
+        self
+    }
+    /// Remove all highlights of a layer
+    pub fn clear(&mut self, layer: &HighlightLayer) -> &mut Self {
+        self.layers.remove(layer);
+        self
+    }
+    /// The highlights of a layer
+    pub fn layer(&self, layer: &HighlightLayer) -> &[Highlight] {
+        self.layers.get(layer).map(|v| v.as_slice()).unwrap_or_default()
+    }
+    /// All layers with their highlights, in drawing order
+    pub fn layers(&self) -> impl Iterator<Item = (&HighlightLayer, &[Highlight])> {
+        self.layers.iter().map(|(layer, highlights)| (layer, highlights.as_slice()))
+    }
+}
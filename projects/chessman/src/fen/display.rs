@@ -0,0 +1,58 @@
+use super::*;
+use crate::square_name;
+
+impl Display for ChessPosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in 0..8 {
+            if y != 0 {
+                write!(f, "/")?;
+            }
+            let mut empty = 0;
+            for x in 0..8 {
+                match self.piece_at(x, y) {
+                    Some(piece) => {
+                        if empty != 0 {
+                            write!(f, "{}", empty)?;
+                            empty = 0;
+                        }
+                        write!(f, "{}", piece.fen_char())?;
+                    }
+                    None => empty += 1,
+                }
+            }
+            if empty != 0 {
+                write!(f, "{}", empty)?;
+            }
+        }
+        let side = match self.side_to_move {
+            PieceColor::White => 'w',
+            PieceColor::Black => 'b',
+        };
+        write!(f, " {} {} ", side, self.castling)?;
+        match self.en_passant {
+            Some((x, y)) => write!(f, "{}", square_name(x, y))?,
+            None => write!(f, "-")?,
+        }
+        write!(f, " {} {}", self.halfmove_clock, self.fullmove_number)
+    }
+}
+
+impl Display for CastlingRights {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let flags = [
+            (self.white_king_side, 'K'),
+            (self.white_queen_side, 'Q'),
+            (self.black_king_side, 'k'),
+            (self.black_queen_side, 'q'),
+        ];
+        if flags.iter().all(|(set, _)| !set) {
+            return write!(f, "-");
+        }
+        for (set, c) in flags.iter() {
+            if *set {
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
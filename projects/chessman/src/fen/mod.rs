@@ -0,0 +1,234 @@
+use crate::pieces::{ChessPiece, PieceColor};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+mod display;
+
+/// A chess position decoded from Forsyth–Edwards Notation
+///
+/// Squares are addressed the same way as [`ChessRender`](crate::ChessRender) grids:
+/// `x` runs from file a to file h and `y` runs from rank 8 down to rank 1.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChessPosition {
+    squares: [Option<ChessPiece>; 64],
+    /// The side to move
+    pub side_to_move: PieceColor,
+    /// Which castling moves are still available
+    pub castling: CastlingRights,
+    /// The square a pawn may capture en passant on
+    pub en_passant: Option<(isize, isize)>,
+    /// Half moves since the last capture or pawn move
+    pub halfmove_clock: u32,
+    /// Number of the full move, starting at 1
+    pub fullmove_number: u32,
+}
+
+/// Castling availability of both sides
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct CastlingRights {
+    pub white_king_side: bool,
+    pub white_queen_side: bool,
+    pub black_king_side: bool,
+    pub black_queen_side: bool,
+}
+
+/// Errors raised when a FEN string is malformed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FenError {
+    /// The string has no piece placement field
+    MissingBoard,
+    /// The string has more fields than the format has, six for FEN and four for SFEN
+    TooManyFields(usize),
+    /// The piece placement field does not describe as many ranks as the board has
+    RankCount {
+        /// Number of ranks described
+        ranks: usize,
+        /// Ranks of the board, 8 for chess, 9 for shogi and 10 for xiangqi
+        expected: usize,
+    },
+    /// A rank does not describe as many files as the board has
+    RankLength {
+        /// Rank number, counting down to 1 at the bottom
+        rank: usize,
+        /// Number of files described
+        files: usize,
+        /// Files of the board, 8 for chess and 9 for shogi and xiangqi
+        expected: usize,
+    },
+    /// An unknown piece letter
+    InvalidPiece(char),
+    /// The side to move is neither `w` nor `b`
+    InvalidSideToMove(String),
+    /// The castling field is neither `-` nor a combination of `KQkq`
+    InvalidCastling(String),
+    /// The en passant field is neither `-` nor a square on rank 3 or 6
+    InvalidEnPassant(String),
+    /// A move counter is not a non-negative number
+    InvalidCounter(String),
+    /// The pieces in hand of an SFEN string are neither `-` nor counts and piece letters
+    InvalidHand(String),
+}
+
+impl Default for ChessPosition {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl ChessPosition {
+    /// FEN of the standard starting position
+    pub const STARTING_FEN: &'static str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+    /// A board without any pieces, white to move
+    pub fn empty() -> Self {
+        Self {
+            squares: [None; 64],
+            side_to_move: PieceColor::White,
+            castling: CastlingRights::default(),
+            en_passant: None,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+        }
+    }
+    /// The standard starting position
+    pub fn starting() -> Self {
+        Self::from_str(Self::STARTING_FEN).expect("starting position is valid")
+    }
+    /// The piece on square `(x, y)`, if any
+    pub fn piece_at(&self, x: isize, y: isize) -> Option<ChessPiece> {
+        if (0..8).contains(&x) && (0..8).contains(&y) {
+            self.squares[(y * 8 + x) as usize]
+        }
+        else {
+            None
+        }
+    }
+    /// Put a piece on square `(x, y)`, or clear it with `None`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the square is outside the board.
+    pub fn set_piece(&mut self, x: isize, y: isize, piece: Option<ChessPiece>) {
+        assert!((0..8).contains(&x) && (0..8).contains(&y), "square ({}, {}) is outside the board", x, y);
+        self.squares[(y * 8 + x) as usize] = piece;
+    }
+    /// All pieces on the board with their squares, rank 8 first
+    pub fn pieces(&self) -> impl Iterator<Item = (isize, isize, ChessPiece)> + '_ {
+        self.squares.iter().enumerate().filter_map(|(i, piece)| piece.map(|p| (i as isize % 8, i as isize / 8, p)))
+    }
+}
+
+impl FromStr for ChessPosition {
+    type Err = FenError;
+
+    fn from_str(fen: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if fields.len() > 6 {
+            return Err(FenError::TooManyFields(fields.len()));
+        }
+        let mut position = ChessPosition::empty();
+        let board = fields.first().ok_or(FenError::MissingBoard)?;
+        let ranks: Vec<&str> = board.split('/').collect();
+        if ranks.len() != 8 {
+            return Err(FenError::RankCount { ranks: ranks.len(), expected: 8 });
+        }
+        for (y, rank) in ranks.iter().enumerate() {
+            let mut x = 0;
+            for c in rank.chars() {
+                match c.to_digit(10) {
+                    Some(skip @ 1..=8) => x += skip as usize,
+                    Some(_) => return Err(FenError::InvalidPiece(c)),
+                    None => {
+                        let piece = ChessPiece::from_fen_char(c).ok_or(FenError::InvalidPiece(c))?;
+                        if x < 8 {
+                            position.squares[y * 8 + x] = Some(piece);
+                        }
+                        x += 1;
+                    }
+                }
+            }
+            if x != 8 {
+                return Err(FenError::RankLength { rank: 8 - y, files: x, expected: 8 });
+            }
+        }
+        if let Some(side) = fields.get(1) {
+            position.side_to_move = match *side {
+                "w" => PieceColor::White,
+                "b" => PieceColor::Black,
+                _ => return Err(FenError::InvalidSideToMove(side.to_string())),
+            };
+        }
+        if let Some(castling) = fields.get(2) {
+            position.castling = castling.parse()?;
+        }
+        if let Some(en_passant) = fields.get(3) {
+            position.en_passant = parse_en_passant(en_passant)?;
+        }
+        if let Some(counter) = fields.get(4) {
+            position.halfmove_clock = counter.parse().map_err(|_| FenError::InvalidCounter(counter.to_string()))?;
+        }
+        if let Some(counter) = fields.get(5) {
+            position.fullmove_number = counter.parse().map_err(|_| FenError::InvalidCounter(counter.to_string()))?;
+        }
+        Ok(position)
+    }
+}
+
+impl FromStr for CastlingRights {
+    type Err = FenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rights = CastlingRights::default();
+        if s == "-" {
+            return Ok(rights);
+        }
+        for c in s.chars() {
+            let flag = match c {
+                'K' => &mut rights.white_king_side,
+                'Q' => &mut rights.white_queen_side,
+                'k' => &mut rights.black_king_side,
+                'q' => &mut rights.black_queen_side,
+                _ => return Err(FenError::InvalidCastling(s.to_string())),
+            };
+            if *flag {
+                return Err(FenError::InvalidCastling(s.to_string()));
+            }
+            *flag = true;
+        }
+        Ok(rights)
+    }
+}
+
+fn parse_en_passant(s: &str) -> Result<Option<(isize, isize)>, FenError> {
+    if s == "-" {
+        return Ok(None);
+    }
+    let bytes = s.as_bytes();
+    match bytes {
+        [file @ b'a'..=b'h', rank @ (b'3' | b'6')] => Ok(Some(((file - b'a') as isize, (b'8' - rank) as isize))),
+        _ => Err(FenError::InvalidEnPassant(s.to_string())),
+    }
+}
+
+impl Display for FenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FenError::MissingBoard => write!(f, "FEN has no piece placement field"),
+            FenError::TooManyFields(n) => write!(f, "FEN has {} fields, more than the format has", n),
+            FenError::RankCount { ranks, expected } => write!(f, "FEN describes {} ranks, expected {}", ranks, expected),
+            FenError::RankLength { rank, files, expected } => {
+                write!(f, "rank {} describes {} files, expected {}", rank, files, expected)
+            }
+            FenError::InvalidPiece(c) => write!(f, "invalid piece `{}`", c),
+            FenError::InvalidSideToMove(s) => write!(f, "invalid side to move `{}`", s),
+            FenError::InvalidCastling(s) => write!(f, "invalid castling rights `{}`", s),
+            FenError::InvalidEnPassant(s) => write!(f, "invalid en passant square `{}`", s),
+            FenError::InvalidHand(s) => write!(f, "invalid pieces in hand `{}`", s),
+            FenError::InvalidCounter(s) => write!(f, "invalid move counter `{}`", s),
+        }
+    }
+}
+
+impl std::error::Error for FenError {}
@@ -0,0 +1,122 @@
+use crate::{ChessPiece, ChessPosition, PieceColor, PieceKind};
+
+/// How many pieces attack every square of a board
+///
+/// Sliding pieces stop at the first occupied square, which they still attack, so a piece
+/// shields the squares behind it.
+///
+/// # Examples
+///
+/// ```
+/// use chessman::{AttackHeatmap, ChessPosition, SquareStatus};
+/// let heatmap = AttackHeatmap::from_position(&ChessPosition::starting());
+/// assert_eq!(heatmap.attacks(5, 5), 3);
+/// assert_eq!(heatmap.status(4, 4), SquareStatus::Safe);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttackHeatmap {
+    width: usize,
+    height: usize,
+    pieces: Vec<(isize, isize, ChessPiece)>,
+    attacks: Vec<u32>,
+}
+
+/// How a square of a heatmap is shaded
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SquareStatus {
+    /// Empty and not attacked
+    Safe,
+    /// Empty and attacked by at least one piece
+    Attacked,
+    /// Holds a piece no other piece attacks
+    Occupied,
+    /// Holds a piece that another piece attacks
+    Contested,
+}
+
+/// Orthogonal steps first, then diagonal ones
+const ALL_DIRECTIONS: [(isize, isize); 8] = [(1, 0), (0, 1), (-1, 0), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)];
+const KNIGHT: [(isize, isize); 8] = [(1, 2), (2, 1), (2, -1), (1, -2), (-1, -2), (-2, -1), (-2, 1), (-1, 2)];
+
+impl AttackHeatmap {
+    /// Count the attacks of pieces on a `width` × `height` board, pieces off the board are ignored
+    pub fn new(width: usize, height: usize, pieces: impl IntoIterator<Item = (isize, isize, ChessPiece)>) -> Self {
+        let on_board = |x: isize, y: isize| x >= 0 && y >= 0 && (x as usize) < width && (y as usize) < height;
+        let pieces: Vec<_> = pieces.into_iter().filter(|&(x, y, _)| on_board(x, y)).collect();
+        let mut occupied = vec![false; width * height];
+        for &(x, y, _) in &pieces {
+            occupied[y as usize * width + x as usize] = true;
+        }
+        let mut attacks = vec![0; width * height];
+        for &(x, y, piece) in &pieces {
+            let (directions, sliding): (&[(isize, isize)], bool) = match piece.kind {
+                PieceKind::King => (&ALL_DIRECTIONS, false),
+                PieceKind::Queen => (&ALL_DIRECTIONS, true),
+                PieceKind::Rook => (&ALL_DIRECTIONS[..4], true),
+                PieceKind::Bishop => (&ALL_DIRECTIONS[4..], true),
+                PieceKind::Knight => (&KNIGHT, false),
+                PieceKind::Pawn if piece.color == PieceColor::White => (&[(-1, -1), (1, -1)], false),
+                PieceKind::Pawn => (&[(-1, 1), (1, 1)], false),
+            };
+            for &(dx, dy) in directions {
+                let (mut tx, mut ty) = (x + dx, y + dy);
+                while on_board(tx, ty) {
+                    let index = ty as usize * width + tx as usize;
+                    attacks[index] += 1;
+                    if !sliding || occupied[index] {
+                        break;
+                    }
+                    tx += dx;
+                    ty += dy;
+                }
+            }
+        }
+        Self { width, height, pieces, attacks }
+    }
+    /// Count the attacks of both sides in a chess position
+    pub fn from_position(position: &ChessPosition) -> Self {
+        Self::new(8, 8, position.pieces())
+    }
+    /// Count the attacks of identical pieces, as in the N-queens family of puzzles
+    pub fn uniform(width: usize, height: usize, kind: PieceKind, squares: impl IntoIterator<Item = (isize, isize)>) -> Self {
+        let piece = ChessPiece::new(kind, PieceColor::White);
+        Self::new(width, height, squares.into_iter().map(|(x, y)| (x, y, piece)))
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// The pieces the attacks were counted for
+    pub fn pieces(&self) -> &[(isize, isize, ChessPiece)] {
+        &self.pieces
+    }
+    /// Number of pieces attacking `(x, y)`, `0` off the board
+    pub fn attacks(&self, x: isize, y: isize) -> u32 {
+        self.index(x, y).map_or(0, |index| self.attacks[index])
+    }
+    /// The highest number of attacks on any square
+    pub fn max_attacks(&self) -> u32 {
+        self.attacks.iter().copied().max().unwrap_or(0)
+    }
+    pub fn status(&self, x: isize, y: isize) -> SquareStatus {
+        let occupied = self.pieces.iter().any(|&(px, py, _)| (px, py) == (x, y));
+        match (occupied, self.attacks(x, y) > 0) {
+            (false, false) => SquareStatus::Safe,
+            (false, true) => SquareStatus::Attacked,
+            (true, false) => SquareStatus::Occupied,
+            (true, true) => SquareStatus::Contested,
+        }
+    }
+    /// Whether no piece attacks another, the goal of the N-queens family of puzzles
+    pub fn is_peaceful(&self) -> bool {
+        self.pieces.iter().all(|&(x, y, _)| self.attacks(x, y) == 0)
+    }
+    fn index(&self, x: isize, y: isize) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        Some(y as usize * self.width + x as usize)
+    }
+}
@@ -0,0 +1,145 @@
+use crate::ChessPiece;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The six neighbours of a cell in axial coordinates
+pub const HEX_DIRECTIONS: [(isize, isize); 6] = [(0, -1), (1, -1), (1, 0), (0, 1), (-1, 1), (-1, 0)];
+
+/// Letters naming the files of a hexagonal board, `j` is left out
+const HEX_FILE_LETTERS: &[u8; 25] = b"abcdefghiklmnopqrstuvwxyz";
+
+/// Hexagonal chess variants and the shape of their boards
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HexVariant {
+    /// 91 cells in a regular hexagon, files `a` to `l`
+    Glinski,
+    /// The same 91 cells as Gliński's board, with a different setup
+    McCooey,
+    /// 70 cells in an irregular hexagon, files `a` to `i`
+    Shafran,
+}
+
+/// A board of hexagonal cells and the pieces standing on them
+///
+/// Cells use axial coordinates `(q, r)` of flat-topped hexagons: `q` counts files from left to
+/// right, `r` counts down a file, and the cell `(q, r + 1)` lies below `(q, r)`. Moving one file
+/// to the right shifts the centers half a cell down, so `(q + 1, r - 1)` is the upper right
+/// neighbour. The center of the board is `(0, 0)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HexBoard {
+    cells: BTreeSet<(isize, isize)>,
+    pieces: BTreeMap<(isize, isize), ChessPiece>,
+}
+
+impl HexVariant {
+    /// The empty board of the variant
+    pub fn board(self) -> HexBoard {
+        match self {
+            HexVariant::Glinski | HexVariant::McCooey => HexBoard::hexagon(5),
+            HexVariant::Shafran => {
+                let cells = (-4..=4).flat_map(|q: isize| ((-5).max(-q - 5)..=4.min(4 - q)).map(move |r| (q, r)));
+                HexBoard::from_cells(cells)
+            }
+        }
+    }
+}
+
+impl HexBoard {
+    /// A regular hexagon of cells at most `radius` steps from the center
+    pub fn hexagon(radius: isize) -> Self {
+        let cells = (-radius..=radius).flat_map(|q| ((-radius).max(-q - radius)..=radius.min(radius - q)).map(move |r| (q, r)));
+        Self::from_cells(cells)
+    }
+    /// A board made of the given cells
+    pub fn from_cells(cells: impl IntoIterator<Item = (isize, isize)>) -> Self {
+        Self { cells: cells.into_iter().collect(), pieces: BTreeMap::new() }
+    }
+    /// Number of cells
+    pub fn count(&self) -> usize {
+        self.cells.len()
+    }
+    pub fn contains(&self, q: isize, r: isize) -> bool {
+        self.cells.contains(&(q, r))
+    }
+    /// All cells, file by file from the left
+    pub fn cells(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.cells.iter().copied()
+    }
+    /// Cells next to `(q, r)` that are on the board
+    pub fn neighbours(&self, q: isize, r: isize) -> impl Iterator<Item = (isize, isize)> + '_ {
+        HEX_DIRECTIONS.iter().map(move |(dq, dr)| (q + dq, r + dr)).filter(move |&(q, r)| self.contains(q, r))
+    }
+    pub fn piece_at(&self, q: isize, r: isize) -> Option<ChessPiece> {
+        self.pieces.get(&(q, r)).copied()
+    }
+    /// Put a piece on a cell, or clear it with `None`
+    ///
+    /// # Panics
+    ///
+    /// If the cell is not on the board.
+    pub fn set_piece(&mut self, q: isize, r: isize, piece: Option<ChessPiece>) -> &mut Self {
+        assert!(self.contains(q, r), "cell ({}, {}) is not on the board", q, r);
+        match piece {
+            Some(piece) => self.pieces.insert((q, r), piece),
+            None => self.pieces.remove(&(q, r)),
+        };
+        self
+    }
+    /// Occupied cells and their pieces
+    pub fn pieces(&self) -> impl Iterator<Item = (isize, isize, ChessPiece)> + '_ {
+        self.pieces.iter().map(|(&(q, r), &piece)| (q, r, piece))
+    }
+    /// Which of the three shades a cell gets: `0` light, `1` middle, `2` dark
+    ///
+    /// Neighbouring cells never share a shade, the center of the board is middle.
+    pub fn cell_shade(q: isize, r: isize) -> usize {
+        (q - r + 1).rem_euclid(3) as usize
+    }
+    /// Lowest cell of file `q`, where its ranks start
+    fn bottom(&self, q: isize) -> Option<isize> {
+        self.cells.range((q, isize::MIN)..=(q, isize::MAX)).next_back().map(|&(_, r)| r)
+    }
+    fn left(&self) -> isize {
+        self.cells.iter().next().map_or(0, |&(q, _)| q)
+    }
+    /// Name of a cell in hex chess notation, such as `f6`
+    ///
+    /// Files are lettered from the left without `j`, continuing with `aa`, `ab` and so on past `z`.
+    /// Ranks count up each file from its lowest cell.
+    pub fn cell_name(&self, q: isize, r: isize) -> Option<String> {
+        if !self.contains(q, r) {
+            return None;
+        }
+        let mut label = vec![];
+        let mut file = (q - self.left()) as usize + 1;
+        while file > 0 {
+            file -= 1;
+            label.push(HEX_FILE_LETTERS[file % HEX_FILE_LETTERS.len()]);
+            file /= HEX_FILE_LETTERS.len();
+        }
+        let letters: String = label.iter().rev().map(|c| *c as char).collect();
+        Some(format!("{}{}", letters, self.bottom(q)? - r + 1))
+    }
+    /// Find a cell from its name in hex chess notation
+    pub fn parse_cell(&self, name: &str) -> Option<(isize, isize)> {
+        let split = name.find(|c: char| c.is_ascii_digit())?;
+        let (letters, rank) = name.split_at(split);
+        if letters.is_empty() {
+            return None;
+        }
+        let mut file = 0usize;
+        for c in letters.bytes() {
+            let index = HEX_FILE_LETTERS.iter().position(|&letter| letter == c)?;
+            file = file.checked_mul(HEX_FILE_LETTERS.len())?.checked_add(index + 1)?;
+        }
+        let file = file - 1;
+        let rank: isize = rank.parse().ok()?;
+        let q = self.left().checked_add(file as isize)?;
+        let r = self.bottom(q)? - rank + 1;
+        if self.contains(q, r) {
+            Some((q, r))
+        }
+        else {
+            None
+        }
+    }
+}
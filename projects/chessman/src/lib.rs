@@ -1,3 +1,35 @@
+mod annotations;
+mod fen;
+mod heatmap;
+mod hex;
+mod moves;
+mod pieces;
+#[cfg(feature = "png")]
+mod raster;
 mod renderer;
+mod shapes;
+mod shogi;
+mod theme;
+mod tours;
+mod xiangqi;
 
-pub use crate::renderer::ChessRender;
+pub use crate::{
+    annotations::{Annotations, Highlight, HighlightLayer, HighlightStyle},
+    fen::{CastlingRights, ChessPosition, FenError},
+    heatmap::{AttackHeatmap, SquareStatus},
+    hex::{HexBoard, HexVariant, HEX_DIRECTIONS},
+    moves::{parse_square, square_name, ChessMove, MoveError, PlayedMove},
+    pieces::{ChessPiece, PieceColor, PieceKind},
+    renderer::{
+        arrow_marker_id, file_label, rank_label, AnimationTiming, Arrow, AsciiBackend, ChessRender, CoordinateStyle, Gallery,
+        Geometry, HtmlViewer, Orientation, RenderBackend, SvgBackend, TerminalBackend,
+    },
+    shapes::BoardShape,
+    shogi::{ShogiKind, ShogiPiece, ShogiPosition},
+    theme::{is_css_color, PieceSet, Theme, ThemeError},
+    tours::{is_knight_move, knights_tour, KnightTour, KnightTourSolver},
+    xiangqi::{XiangqiKind, XiangqiPiece, XiangqiPosition},
+};
+
+#[cfg(feature = "png")]
+pub use crate::raster::{render_png, RasterError, Rasterizer};
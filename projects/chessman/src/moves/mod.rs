@@ -0,0 +1,180 @@
+use crate::{ChessPiece, ChessPosition, PieceColor, PieceKind};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// A move in UCI coordinate notation, such as `e2e4` or `e7e8q`
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ChessMove {
+    pub from: (isize, isize),
+    pub to: (isize, isize),
+    /// The piece a pawn promotes to
+    pub promotion: Option<PieceKind>,
+}
+
+/// What happened on the board when a move was played
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PlayedMove {
+    /// The piece that moved
+    pub piece: ChessPiece,
+    /// The square of the captured piece, which differs from the target square en passant
+    pub captured: Option<(isize, isize)>,
+    /// The rook move that goes with castling
+    pub rook: Option<((isize, isize), (isize, isize))>,
+    /// The piece a pawn promoted to
+    pub promotion: Option<ChessPiece>,
+}
+
+/// Errors raised when a move can not be read or played
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MoveError {
+    /// The move is not in UCI coordinate notation
+    Notation(String),
+    /// There is no piece on the origin square
+    EmptySquare(String),
+    /// The origin or target square `(x, y)` is outside of the board
+    OutOfBoard((isize, isize)),
+}
+
+/// Name of square `(x, y)` on a chess board, such as `e4`
+///
+/// Squares outside of the board are written as `(x, y)`.
+pub fn square_name(x: isize, y: isize) -> String {
+    match (x, y) {
+        (0..=7, 0..=7) => format!("{}{}", (b'a' + x as u8) as char, 8 - y),
+        _ => format!("({}, {})", x, y),
+    }
+}
+
+/// Parse a square name such as `e4` into `(x, y)`
+pub fn parse_square(name: &str) -> Option<(isize, isize)> {
+    match name.as_bytes() {
+        [file @ b'a'..=b'h', rank @ b'1'..=b'8'] => Some(((file - b'a') as isize, (b'8' - rank) as isize)),
+        _ => None,
+    }
+}
+
+impl ChessMove {
+    pub fn new(from: (isize, isize), to: (isize, isize)) -> Self {
+        Self { from, to, promotion: None }
+    }
+}
+
+impl FromStr for ChessMove {
+    type Err = MoveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || MoveError::Notation(s.to_string());
+        if !s.is_ascii() || (s.len() != 4 && s.len() != 5) {
+            return Err(error());
+        }
+        let from = parse_square(&s[0..2]).ok_or_else(error)?;
+        let to = parse_square(&s[2..4]).ok_or_else(error)?;
+        let promotion = match s[4..].chars().next() {
+            Some(c) => match PieceKind::from_fen_char(c) {
+                Some(kind @ (PieceKind::Queen | PieceKind::Rook | PieceKind::Bishop | PieceKind::Knight)) => Some(kind),
+                _ => return Err(error()),
+            },
+            None => None,
+        };
+        Ok(Self { from, to, promotion })
+    }
+}
+
+impl Display for ChessMove {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", square_name(self.from.0, self.from.1), square_name(self.to.0, self.to.1))?;
+        if let Some(kind) = self.promotion {
+            write!(f, "{}", kind.fen_char())?;
+        }
+        Ok(())
+    }
+}
+
+impl ChessPosition {
+    /// Play a move without checking that it is legal
+    ///
+    /// Castling, en passant and promotion are recognized from the piece that moves, and the
+    /// side to move, castling rights, en passant square and move counters are updated.
+    pub fn play(&mut self, chess_move: &ChessMove) -> Result<PlayedMove, MoveError> {
+        let ChessMove { from, to, promotion } = *chess_move;
+        if let Some(&square) = [from, to].iter().find(|(x, y)| !(0..8).contains(x) || !(0..8).contains(y)) {
+            return Err(MoveError::OutOfBoard(square));
+        }
+        let piece = self.piece_at(from.0, from.1).ok_or_else(|| MoveError::EmptySquare(square_name(from.0, from.1)))?;
+        let mut played = PlayedMove { piece, captured: None, rook: None, promotion: None };
+        if self.piece_at(to.0, to.1).is_some() {
+            played.captured = Some(to);
+        }
+        match piece.kind {
+            PieceKind::Pawn if from.0 != to.0 && played.captured.is_none() && self.en_passant == Some(to) => {
+                played.captured = Some((to.0, from.1));
+            }
+            PieceKind::King if (to.0 - from.0).abs() == 2 => {
+                let (rook_from, rook_to) = if to.0 > from.0 { (7, to.0 - 1) } else { (0, to.0 + 1) };
+                played.rook = Some(((rook_from, from.1), (rook_to, from.1)));
+            }
+            _ => {}
+        }
+        if piece.kind == PieceKind::Pawn && (to.1 == 0 || to.1 == 7) {
+            played.promotion = Some(ChessPiece::new(promotion.unwrap_or(PieceKind::Queen), piece.color));
+        }
+        // update the board
+        if let Some((x, y)) = played.captured {
+            self.set_piece(x, y, None);
+        }
+        self.set_piece(from.0, from.1, None);
+        self.set_piece(to.0, to.1, Some(played.promotion.unwrap_or(piece)));
+        if let Some((rook_from, rook_to)) = played.rook {
+            let rook = self.piece_at(rook_from.0, rook_from.1);
+            self.set_piece(rook_from.0, rook_from.1, None);
+            self.set_piece(rook_to.0, rook_to.1, rook);
+        }
+        // update the state
+        for square in [from, to].iter() {
+            match square {
+                (4, 7) => {
+                    self.castling.white_king_side = false;
+                    self.castling.white_queen_side = false;
+                }
+                (4, 0) => {
+                    self.castling.black_king_side = false;
+                    self.castling.black_queen_side = false;
+                }
+                (7, 7) => self.castling.white_king_side = false,
+                (0, 7) => self.castling.white_queen_side = false,
+                (7, 0) => self.castling.black_king_side = false,
+                (0, 0) => self.castling.black_queen_side = false,
+                _ => {}
+            }
+        }
+        self.en_passant = match piece.kind {
+            PieceKind::Pawn if (to.1 - from.1).abs() == 2 => Some((from.0, (from.1 + to.1) / 2)),
+            _ => None,
+        };
+        if piece.kind == PieceKind::Pawn || played.captured.is_some() {
+            self.halfmove_clock = 0;
+        }
+        else {
+            self.halfmove_clock += 1;
+        }
+        if piece.color == PieceColor::Black {
+            self.fullmove_number += 1;
+        }
+        self.side_to_move = piece.color.opponent();
+        Ok(played)
+    }
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoveError::Notation(s) => write!(f, "`{}` is not a move in coordinate notation", s),
+            MoveError::EmptySquare(s) => write!(f, "there is no piece on {}", s),
+            MoveError::OutOfBoard((x, y)) => write!(f, "square ({}, {}) is outside of the board", x, y),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
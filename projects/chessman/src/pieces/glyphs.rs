@@ -0,0 +1,92 @@
+use super::PieceKind;
+
+/// Side length of the square every glyph is drawn in
+pub const GLYPH_SIZE: f32 = 45.0;
+
+/// One outline of a piece glyph.
+///
+/// The glyphs are drawn for this crate and ship under its license.
+#[derive(Copy, Clone, Debug)]
+pub enum GlyphPart {
+    /// A closed shape, filled with the piece color and outlined
+    Body(&'static str),
+    /// An open stroke drawn on top of the body in the contrast color
+    Detail(&'static str),
+}
+
+const BASE: GlyphPart = GlyphPart::Body("M 10 39.5 L 35 39.5 L 35 36 L 10 36 Z");
+
+const KING: &[GlyphPart] = &[
+    GlyphPart::Body("M 21 22 L 21 14 L 24 14 L 24 22 Z"),
+    GlyphPart::Body(
+        "M 21 14 L 21 10.5 L 18 10.5 L 18 7.5 L 21 7.5 L 21 4.5 L 24 4.5 L 24 7.5 L 27 7.5 L 27 10.5 L 24 10.5 L 24 14 Z",
+    ),
+    GlyphPart::Body(
+        "M 11.5 36 L 33.5 36 L 33 31 C 37.5 26 38.5 18.5 32 16.5 C 27.5 15.5 24.5 19 22.5 23 \
+         C 20.5 19 17.5 15.5 13 16.5 C 6.5 18.5 7.5 26 12 31 Z",
+    ),
+    BASE,
+    GlyphPart::Detail("M 12 31 L 33 31"),
+    GlyphPart::Detail("M 22.5 23 L 22.5 31"),
+];
+
+const QUEEN: &[GlyphPart] = &[
+    GlyphPart::Body(
+        "M 11.5 36 L 33.5 36 L 33 31 L 37 15 L 30 26 L 29.5 11 L 25 25 L 22.5 9.5 \
+         L 20 25 L 15.5 11 L 15 26 L 8 15 L 12 31 Z",
+    ),
+    GlyphPart::Body("M 10.2 15 A 2.2 2.2 0 1 1 5.8 15 A 2.2 2.2 0 1 1 10.2 15 Z"),
+    GlyphPart::Body("M 17.7 11 A 2.2 2.2 0 1 1 13.3 11 A 2.2 2.2 0 1 1 17.7 11 Z"),
+    GlyphPart::Body("M 24.7 9.5 A 2.2 2.2 0 1 1 20.3 9.5 A 2.2 2.2 0 1 1 24.7 9.5 Z"),
+    GlyphPart::Body("M 31.7 11 A 2.2 2.2 0 1 1 27.3 11 A 2.2 2.2 0 1 1 31.7 11 Z"),
+    GlyphPart::Body("M 39.2 15 A 2.2 2.2 0 1 1 34.8 15 A 2.2 2.2 0 1 1 39.2 15 Z"),
+    BASE,
+    GlyphPart::Detail("M 12 31 L 33 31"),
+];
+
+const ROOK: &[GlyphPart] = &[
+    GlyphPart::Body("M 14 32 L 31 32 L 29.5 17 L 15.5 17 Z"),
+    GlyphPart::Body("M 12 35.5 L 33 35.5 L 33 32 L 12 32 Z"),
+    GlyphPart::Body("M 13 17 L 32 17 L 32 14 L 13 14 Z"),
+    GlyphPart::Body("M 13 14 L 13 9 L 17 9 L 17 11 L 20.5 11 L 20.5 9 L 24.5 9 L 24.5 11 L 28 11 L 28 9 L 32 9 L 32 14 Z"),
+    GlyphPart::Body("M 9 39.5 L 36 39.5 L 36 35.5 L 9 35.5 Z"),
+];
+
+const BISHOP: &[GlyphPart] = &[
+    GlyphPart::Body("M 16 36 L 29 36 L 27 31 L 18 31 Z"),
+    GlyphPart::Body("M 15.5 31 L 29.5 31 L 29.5 28.5 L 15.5 28.5 Z"),
+    GlyphPart::Body("M 17 28.5 C 14 24 15 17 22.5 11 C 30 17 31 24 28 28.5 Z"),
+    GlyphPart::Body("M 25 8.5 A 2.5 2.5 0 1 1 20 8.5 A 2.5 2.5 0 1 1 25 8.5 Z"),
+    BASE,
+    GlyphPart::Detail("M 25.5 16 L 21 22.5"),
+];
+
+const KNIGHT: &[GlyphPart] = &[
+    GlyphPart::Body(
+        "M 13 36 C 13 29 17 25 20 22 C 17 23 14 25 12 27.5 C 10 28 8.5 26.5 9 24.5 \
+         C 10 21 13 18 15.5 15 C 16.5 13 17 11 18 9 L 20 11 L 22 8.5 L 23.5 11 \
+         C 30 12 34 17 34 25 L 34 36 Z",
+    ),
+    BASE,
+    GlyphPart::Detail("M 17.5 15.5 L 19 15"),
+    GlyphPart::Detail("M 24 12.5 C 28.5 15 30.5 20 30.5 27"),
+];
+
+const PAWN: &[GlyphPart] = &[
+    GlyphPart::Body("M 15 36 C 15 30 18 26 20.5 23.5 L 24.5 23.5 C 27 26 30 30 30 36 Z"),
+    GlyphPart::Body("M 17.5 24 L 27.5 24 L 27.5 21.5 L 17.5 21.5 Z"),
+    GlyphPart::Body("M 28 16 A 5.5 5.5 0 1 1 17 16 A 5.5 5.5 0 1 1 28 16 Z"),
+    BASE,
+];
+
+/// Outlines of a piece, from back to front
+pub fn piece_glyph(kind: PieceKind) -> &'static [GlyphPart] {
+    match kind {
+        PieceKind::King => KING,
+        PieceKind::Queen => QUEEN,
+        PieceKind::Rook => ROOK,
+        PieceKind::Bishop => BISHOP,
+        PieceKind::Knight => KNIGHT,
+        PieceKind::Pawn => PAWN,
+    }
+}
@@ -0,0 +1,87 @@
+mod glyphs;
+
+pub(crate) use self::glyphs::{piece_glyph, GlyphPart, GLYPH_SIZE};
+
+/// The six kinds of orthodox chess pieces
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PieceKind {
+    King,
+    Queen,
+    Rook,
+    Bishop,
+    Knight,
+    Pawn,
+}
+
+/// The side a piece belongs to
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PieceColor {
+    White,
+    Black,
+}
+
+/// A piece of a given kind and color
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ChessPiece {
+    pub kind: PieceKind,
+    pub color: PieceColor,
+}
+
+impl ChessPiece {
+    pub fn new(kind: PieceKind, color: PieceColor) -> Self {
+        Self { kind, color }
+    }
+    /// Parse a FEN piece letter, uppercase for white and lowercase for black
+    pub fn from_fen_char(c: char) -> Option<Self> {
+        let kind = PieceKind::from_fen_char(c)?;
+        let color = if c.is_ascii_uppercase() { PieceColor::White } else { PieceColor::Black };
+        Some(Self { kind, color })
+    }
+    /// The FEN piece letter, uppercase for white and lowercase for black
+    pub fn fen_char(self) -> char {
+        match self.color {
+            PieceColor::White => self.kind.fen_char().to_ascii_uppercase(),
+            PieceColor::Black => self.kind.fen_char(),
+        }
+    }
+}
+
+impl PieceKind {
+    /// All piece kinds, from the king down to the pawn
+    pub const ALL: [PieceKind; 6] =
+        [PieceKind::King, PieceKind::Queen, PieceKind::Rook, PieceKind::Bishop, PieceKind::Knight, PieceKind::Pawn];
+
+    /// Parse a FEN piece letter, ignoring its case
+    pub fn from_fen_char(c: char) -> Option<Self> {
+        match c.to_ascii_lowercase() {
+            'k' => Some(PieceKind::King),
+            'q' => Some(PieceKind::Queen),
+            'r' => Some(PieceKind::Rook),
+            'b' => Some(PieceKind::Bishop),
+            'n' => Some(PieceKind::Knight),
+            'p' => Some(PieceKind::Pawn),
+            _ => None,
+        }
+    }
+    /// The lowercase FEN piece letter
+    pub fn fen_char(self) -> char {
+        match self {
+            PieceKind::King => 'k',
+            PieceKind::Queen => 'q',
+            PieceKind::Rook => 'r',
+            PieceKind::Bishop => 'b',
+            PieceKind::Knight => 'n',
+            PieceKind::Pawn => 'p',
+        }
+    }
+}
+
+impl PieceColor {
+    /// The opposing side
+    pub fn opponent(self) -> Self {
+        match self {
+            PieceColor::White => PieceColor::Black,
+            PieceColor::Black => PieceColor::White,
+        }
+    }
+}
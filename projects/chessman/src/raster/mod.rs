@@ -0,0 +1,97 @@
+use resvg::{
+    tiny_skia::{Pixmap, Transform},
+    usvg::{fontdb::Database, Options, Tree},
+};
+use std::{
+    fmt::{Display, Formatter},
+    sync::Arc,
+};
+use svg::Document;
+
+/// DejaVu Sans, see `assets/DejaVuSans-LICENSE.txt`
+static DEJAVU_SANS: &[u8] = include_bytes!("../../assets/DejaVuSans.ttf");
+
+/// Family every generic font name resolves to
+const FONT_FAMILY: &str = "DejaVu Sans";
+
+/// Turns rendered documents into PNG images
+///
+/// Only embedded fonts are used, never the fonts installed on the machine, so the same
+/// document gives the same pixels everywhere.
+#[derive(Clone, Debug)]
+pub struct Rasterizer {
+    fonts: Arc<Database>,
+}
+
+/// Errors raised while rasterizing a document
+#[derive(Debug)]
+pub enum RasterError {
+    /// The document could not be parsed back as SVG
+    Svg(String),
+    /// The requested or computed image size is empty or too large
+    Size { width: u32, height: u32 },
+    /// The image could not be encoded as PNG
+    Encode(String),
+}
+
+impl Default for Rasterizer {
+    fn default() -> Self {
+        let mut fonts = Database::new();
+        fonts.load_font_data(DEJAVU_SANS.to_vec());
+        fonts.set_serif_family(FONT_FAMILY);
+        fonts.set_sans_serif_family(FONT_FAMILY);
+        fonts.set_monospace_family(FONT_FAMILY);
+        fonts.set_cursive_family(FONT_FAMILY);
+        fonts.set_fantasy_family(FONT_FAMILY);
+        Self { fonts: Arc::new(fonts) }
+    }
+}
+
+impl Rasterizer {
+    /// Embed another font, e.g. one with CJK glyphs for xiangqi or shogi boards
+    pub fn add_font(&mut self, data: Vec<u8>) {
+        Arc::make_mut(&mut self.fonts).load_font_data(data);
+    }
+    /// Rasterize a document to a PNG image `width` pixels wide, keeping its aspect ratio
+    pub fn render_png(&self, document: &Document, width: u32) -> Result<Vec<u8>, RasterError> {
+        let pixmap = self.render_pixmap(document, width)?;
+        pixmap.encode_png().map_err(|e| RasterError::Encode(e.to_string()))
+    }
+    /// Rasterize a document to raw RGBA pixels `width` pixels wide, keeping its aspect ratio
+    pub fn render_pixmap(&self, document: &Document, width: u32) -> Result<Pixmap, RasterError> {
+        let options = Options { font_family: FONT_FAMILY.to_string(), fontdb: self.fonts.clone(), ..Options::default() };
+        let tree = Tree::from_str(&document.to_string(), &options).map_err(|e| RasterError::Svg(e.to_string()))?;
+        let size = tree.size();
+        let scale = width as f32 / size.width();
+        let height = (size.height() * scale).round() as u32;
+        let mut pixmap = Pixmap::new(width, height).ok_or(RasterError::Size { width, height })?;
+        resvg::render(&tree, Transform::from_scale(scale, scale), &mut pixmap.as_mut());
+        Ok(pixmap)
+    }
+}
+
+/// Rasterize a document to a PNG image `width` pixels wide with the embedded fonts
+///
+/// # Examples
+///
+/// ```
+/// use chessman::{render_png, ChessRender};
+/// let document = ChessRender::default().render_fen("8/8/8/4k3/8/8/8/4K3").unwrap();
+/// let png = render_png(&document, 256).unwrap();
+/// assert!(png.starts_with(b"\x89PNG"));
+/// ```
+pub fn render_png(document: &Document, width: u32) -> Result<Vec<u8>, RasterError> {
+    Rasterizer::default().render_png(document, width)
+}
+
+impl Display for RasterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RasterError::Svg(e) => write!(f, "invalid svg: {}", e),
+            RasterError::Size { width, height } => write!(f, "can not create a {}×{} image", width, height),
+            RasterError::Encode(e) => write!(f, "can not encode png: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RasterError {}
@@ -0,0 +1,204 @@
+use super::*;
+use crate::{ChessMove, ChessPiece, ChessPosition, MoveError};
+use std::collections::BTreeMap;
+use svg::{
+    node::element::{Animate, AnimateTransform},
+    Node,
+};
+
+/// Durations of an animated replay, in seconds
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AnimationTiming {
+    /// Time a piece takes to slide to its target, or a path to reach the next point
+    pub move_duration: f32,
+    /// Time to wait before every move
+    pub pause: f32,
+    /// Start over at the end instead of freezing on the last frame
+    pub repeat: bool,
+}
+
+impl Default for AnimationTiming {
+    fn default() -> Self {
+        Self { move_duration: 0.5, pause: 0.5, repeat: false }
+    }
+}
+
+impl AnimationTiming {
+    /// Length of a replay of `steps` moves
+    pub fn total(&self, steps: usize) -> f32 {
+        self.pause + steps as f32 * (self.move_duration + self.pause)
+    }
+    /// When the `step`-th move starts and ends
+    pub fn step(&self, step: usize) -> (f32, f32) {
+        let start = self.pause + step as f32 * (self.move_duration + self.pause);
+        (start, start + self.move_duration)
+    }
+    fn key_times(&self, times: &[f32], total: f32) -> String {
+        let times: Vec<String> = times.iter().map(|time| (time / total.max(f32::EPSILON)).min(1.0).to_string()).collect();
+        times.join(";")
+    }
+    fn apply<T: Node>(&self, mut animation: T, total: f32) -> T {
+        animation.assign("dur", format!("{}s", total));
+        if self.repeat {
+            animation.assign("repeatCount", "indefinite");
+        }
+        else {
+            animation.assign("fill", "freeze");
+        }
+        animation
+    }
+    /// Switch `attribute` from `before` to `after` at `time`
+    fn switch(&self, attribute: &str, before: &str, after: &str, time: f32, total: f32) -> Animate {
+        let animation = Animate::new()
+            .set("attributeName", attribute)
+            .set("calcMode", "discrete")
+            .set("values", format!("{};{}", before, after))
+            .set("keyTimes", self.key_times(&[0.0, time], total));
+        self.apply(animation, total)
+    }
+}
+
+/// A piece and where it stands after every move
+struct Sprite {
+    piece: ChessPiece,
+    squares: Vec<(isize, isize)>,
+    /// The move after which a promoted piece appears
+    appear: Option<usize>,
+    /// The move at the end of which the piece is captured or promoted
+    vanish: Option<usize>,
+}
+
+impl ChessRender {
+    /// Render a position, then slide its pieces through `moves` with SMIL animations
+    ///
+    /// Without animation support the document shows the start position.
+    pub fn animate_moves(
+        &self,
+        position: &ChessPosition,
+        moves: &[ChessMove],
+        timing: &AnimationTiming,
+    ) -> Result<Document, MoveError> {
+        let mut sprites = vec![];
+        let mut board = BTreeMap::new();
+        for (x, y, piece) in position.pieces() {
+            board.insert((x, y), sprites.len());
+            sprites.push(Sprite { piece, squares: vec![(x, y)], appear: None, vanish: None });
+        }
+        let mut current = position.clone();
+        for (step, chess_move) in moves.iter().enumerate() {
+            let played = current.play(chess_move)?;
+            for sprite in sprites.iter_mut() {
+                let last = sprite.squares[step];
+                sprite.squares.push(last);
+            }
+            if let Some(captured) = played.captured.and_then(|square| board.remove(&square)) {
+                sprites[captured].vanish = Some(step);
+            }
+            if let Some((rook_from, rook_to)) = played.rook {
+                if let Some(rook) = board.remove(&rook_from) {
+                    sprites[rook].squares[step + 1] = rook_to;
+                    board.insert(rook_to, rook);
+                }
+            }
+            if let Some(mover) = board.remove(&chess_move.from) {
+                sprites[mover].squares[step + 1] = chess_move.to;
+                board.insert(chess_move.to, mover);
+            }
+            if let Some(piece) = played.promotion {
+                // the pawn slides onto the last rank and is replaced when it arrives
+                if let Some(pawn) = board.insert(chess_move.to, sprites.len()) {
+                    sprites[pawn].vanish = Some(step);
+                }
+                let squares = vec![chess_move.to; step + 2];
+                sprites.push(Sprite { piece, squares, appear: Some(step), vanish: None });
+            }
+        }
+        let mut document = self.document(8.0, 8.0);
+        if self.compact && !self.shared_definitions {
+            let pieces = sprites.iter().map(|sprite| (sprite.piece.kind, sprite.piece.color));
+            document = document.add(self.draw_definitions(pieces));
+        }
+        for y in 0..8 {
+            for x in 0..8 {
+                document = document.add(self.draw_square(x, y));
+            }
+        }
+        document = document.add(self.draw_coordinates(8, 8));
+        let total = timing.total(moves.len());
+        for sprite in &sprites {
+            document = document.add(self.animate_sprite(sprite, timing, total));
+        }
+        Ok(document)
+    }
+    fn animate_sprite(&self, sprite: &Sprite, timing: &AnimationTiming, total: f32) -> Group {
+        let (x, y) = sprite.squares[0];
+        let origin = self.square_center(x, y);
+        let offset = |&(x, y): &(isize, isize)| {
+            let (cx, cy) = self.square_center(x, y);
+            format!("{} {}", cx - origin.0, cy - origin.1)
+        };
+        let mut times = vec![0.0];
+        let mut values = vec![offset(&sprite.squares[0])];
+        for (step, squares) in sprite.squares.windows(2).enumerate() {
+            if squares[0] != squares[1] {
+                let (start, end) = timing.step(step);
+                times.extend_from_slice(&[start, end]);
+                values.extend_from_slice(&[offset(&squares[0]), offset(&squares[1])]);
+            }
+        }
+        times.push(total);
+        values.push(offset(&sprite.squares[sprite.squares.len() - 1]));
+        let motion = AnimateTransform::new()
+            .set("attributeName", "transform")
+            .set("type", "translate")
+            .set("values", values.join(";"))
+            .set("keyTimes", timing.key_times(&times, total));
+        let mut group = Group::new().add(self.draw_piece(sprite.piece.kind, sprite.piece.color, x, y));
+        if times.len() > 2 {
+            group = group.add(timing.apply(motion, total));
+        }
+        if let Some(step) = sprite.appear {
+            group = group.set("opacity", 0).add(timing.switch("opacity", "0", "1", timing.step(step).1, total));
+        }
+        if let Some(step) = sprite.vanish {
+            group = group.add(timing.switch("opacity", "1", "0", timing.step(step).1, total));
+        }
+        group
+    }
+    /// Draw a sequence of points, such as a knight's tour, appearing one step at a time
+    ///
+    /// Every segment grows from the previous point before the next point and its step number appear.
+    /// Without animation support the group shows the complete sequence.
+    pub fn animate_path(&self, points: &[(isize, isize)], timing: &AnimationTiming) -> Group {
+        let total = timing.total(points.len().saturating_sub(1));
+        let mut lines = Group::new();
+        let mut marks = Group::new();
+        for (step, &(x, y)) in points.iter().enumerate() {
+            let appear = match step {
+                0 => 0.0,
+                _ => timing.step(step - 1).1,
+            };
+            if step > 0 {
+                let (x0, y0) = points[step - 1];
+                let (start, end) = timing.step(step - 1);
+                let (cx0, cy0) = self.square_center(x0, y0);
+                let (cx, cy) = self.square_center(x, y);
+                let mut line = self.draw_path(x0, y0, x, y);
+                for (attribute, from, to) in [("x2", cx0, cx), ("y2", cy0, cy)].iter() {
+                    let grow = Animate::new()
+                        .set("attributeName", *attribute)
+                        .set("values", format!("{};{};{};{}", from, from, to, to))
+                        .set("keyTimes", timing.key_times(&[0.0, start, end, total], total));
+                    line = line.add(timing.apply(grow, total));
+                }
+                lines = lines.add(line.add(timing.switch("opacity", "0", "1", start, total)));
+            }
+            let mark = Group::new()
+                .add(self.draw_point(x, y))
+                .add(self.draw_step(x, y, step + 1))
+                .add(timing.switch("opacity", "0", "1", appear, total));
+            marks = marks.add(mark);
+        }
+        Group::new().add(lines).add(marks)
+    }
+}
@@ -0,0 +1,133 @@
+use super::*;
+use svg::node::element::{Definitions, Marker};
+
+/// An annotation arrow between two squares
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Arrow {
+    pub from: (isize, isize),
+    pub to: (isize, isize),
+    /// Overrides [`ChessRender::arrow_color`]
+    pub color: Option<String>,
+}
+
+/// Length of the arrowhead, in stroke widths
+const HEAD_LENGTH: f32 = 3.0;
+
+impl Arrow {
+    pub fn new(from: (isize, isize), to: (isize, isize)) -> Self {
+        Self { from, to, color: None }
+    }
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+    /// Whether the arrow describes a knight move, and gets drawn bent
+    pub fn is_knight_move(&self) -> bool {
+        let dx = (self.to.0 - self.from.0).abs();
+        let dy = (self.to.1 - self.from.1).abs();
+        (dx == 1 && dy == 2) || (dx == 2 && dy == 1)
+    }
+}
+
+/// Id of the arrowhead marker filled with `color`
+pub fn arrow_marker_id(color: &str) -> String {
+    format!("arrowhead-{}", id_name(color))
+}
+
+/// A color or name made safe for use in an element id
+pub(super) fn id_name(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c
+            }
+            else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+impl ChessRender {
+    /// The arrowhead referenced by arrows of the given color, it must be added to the document once
+    pub fn arrow_marker(&self, color: &str) -> Marker {
+        let data = Data::new().move_to((0, 0)).line_to((HEAD_LENGTH, HEAD_LENGTH / 2.0)).line_to((0, HEAD_LENGTH)).close();
+        Marker::new()
+            .set("id", arrow_marker_id(color))
+            .set("viewBox", (0, 0, HEAD_LENGTH, HEAD_LENGTH))
+            .set("markerUnits", "strokeWidth")
+            .set("markerWidth", HEAD_LENGTH)
+            .set("markerHeight", HEAD_LENGTH)
+            .set("refX", 0)
+            .set("refY", HEAD_LENGTH / 2.0)
+            .set("orient", "auto")
+            .add(Path::new().set("d", data).set("fill", color))
+    }
+    /// Draw an arrow from square `(x1, y1)` that ends on the near edge of square `(x2, y2)`
+    ///
+    /// Knight moves on square boards are drawn as L-shaped arrows, running along the longer leg first.
+    /// The arrowhead is the marker returned by [`ChessRender::arrow_marker`] for the same color.
+    pub fn draw_arrow(&self, x1: isize, y1: isize, x2: isize, y2: isize, color: &str) -> Path {
+        let arrow = Arrow::new((x1, y1), (x2, y2));
+        let start = self.square_center(x1, y1);
+        let end = self.square_center(x2, y2);
+        let mut data = Data::new().move_to(start);
+        let corner = if !arrow.is_knight_move() || self.geometry == Geometry::Hex {
+            start
+        }
+        else if (x2 - x1).abs() == 2 {
+            self.square_center(x2, y1)
+        }
+        else {
+            self.square_center(x1, y2)
+        };
+        if corner != start {
+            data = data.line_to(corner);
+        }
+        data = data.line_to(self.arrow_tail(corner, end));
+        Path::new()
+            .set("d", data)
+            .set("fill", "none")
+            .set("stroke", color)
+            .set("stroke-width", self.arrow_width)
+            .set("stroke-linejoin", "round")
+            .set("marker-end", format!("url(#{})", arrow_marker_id(color)))
+    }
+    /// Draw a set of arrows together with the arrowheads they use
+    ///
+    /// With [`shared_definitions`](Self::shared_definitions) the arrowheads are left to the page.
+    pub fn draw_arrows(&self, arrows: &[Arrow]) -> Group {
+        let mut colors: Vec<&str> = vec![];
+        let mut group = Group::new();
+        for arrow in arrows {
+            let color = arrow.color.as_deref().unwrap_or(&self.theme.arrow_color);
+            if !colors.contains(&color) {
+                colors.push(color);
+            }
+            group = group.add(self.draw_arrow(arrow.from.0, arrow.from.1, arrow.to.0, arrow.to.1, color));
+        }
+        if self.shared_definitions {
+            return group;
+        }
+        let mut definitions = Definitions::new();
+        for color in colors {
+            definitions = definitions.add(self.arrow_marker(color));
+        }
+        Group::new().add(definitions).add(group)
+    }
+    /// Where the shaft of the last arrow segment ends, so that the head touches the square edge
+    fn arrow_tail(&self, (x1, y1): (f32, f32), (x2, y2): (f32, f32)) -> (f32, f32) {
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let length = dx.hypot(dy);
+        if length == 0.0 {
+            return (x2, y2);
+        }
+        let (ux, uy) = (dx / length, dy / length);
+        let head = HEAD_LENGTH * self.arrow_width;
+        // distance from the square center to its edge along the arrow, keeping a short shaft
+        let edge = self.grid_size / 2.0 / ux.abs().max(uy.abs());
+        let inset = edge.min(length - head - self.arrow_width / 2.0).max(0.0);
+        let tail = (length - inset - head).max(0.0);
+        (x1 + ux * tail, y1 + uy * tail)
+    }
+}
@@ -0,0 +1,221 @@
+use super::*;
+use crate::{Annotations, BoardShape, CastlingRights, ChessPiece, ChessPosition, Highlight, KnightTour};
+use svg::Node;
+
+/// Target of the drawing operations of a [`ChessRender`]
+///
+/// The renderer decides what goes where on the board, the backend how it looks.
+/// Backends that can not draw an element, like lines in a terminal, leave it out.
+pub trait RenderBackend {
+    /// The finished diagram
+    type Output;
+    /// Start a `width` × `height` board, called once before anything else is drawn
+    fn begin(&mut self, render: &ChessRender, width: isize, height: isize);
+    /// Draw the empty square `(x, y)`, squares never drawn are missing from the board
+    fn square(&mut self, x: isize, y: isize);
+    /// Draw a piece on the square `(x, y)`
+    fn piece(&mut self, piece: ChessPiece, x: isize, y: isize);
+    /// Draw a line of a path between the centers of two squares
+    fn path(&mut self, from: (isize, isize), to: (isize, isize));
+    /// Mark the square `(x, y)` as a point of a path
+    fn point(&mut self, x: isize, y: isize);
+    /// Number the square `(x, y)` with its step on a path
+    fn step(&mut self, x: isize, y: isize, step: usize);
+    /// Mark a square in the given color, below the pieces
+    ///
+    /// Ignored by default, like the other annotations and indicators.
+    fn highlight(&mut self, _highlight: &Highlight, _color: &str) {}
+    /// Draw an arrow above the pieces
+    fn arrow(&mut self, _arrow: &Arrow) {}
+    /// Mark the rooks that can still castle
+    fn castling(&mut self, _rights: &CastlingRights) {}
+    /// Show whose turn it is beside the board
+    fn side_to_move(&mut self, _color: PieceColor) {}
+    /// Finish the diagram, adding the file and rank labels if enabled
+    fn finish(self) -> Self::Output;
+}
+
+/// Draws svg documents, with the same elements as the `render_*` methods
+#[derive(Clone, Debug)]
+pub struct SvgBackend {
+    render: ChessRender,
+    shape: BoardShape,
+    kinds: Vec<(PieceKind, PieceColor)>,
+    highlights: Option<Group>,
+    castling: Option<Group>,
+    paths: Group,
+    marks: Group,
+    pieces: Group,
+    arrows: Vec<Arrow>,
+    side_to_move: Option<PieceColor>,
+}
+
+impl Default for SvgBackend {
+    fn default() -> Self {
+        Self {
+            render: ChessRender::default(),
+            shape: BoardShape::empty(0, 0),
+            kinds: vec![],
+            highlights: None,
+            castling: None,
+            paths: Group::new(),
+            marks: Group::new(),
+            pieces: Group::new(),
+            arrows: vec![],
+            side_to_move: None,
+        }
+    }
+}
+
+impl RenderBackend for SvgBackend {
+    type Output = Document;
+
+    fn begin(&mut self, render: &ChessRender, width: isize, height: isize) {
+        *self = Self { render: render.clone(), shape: BoardShape::empty(width as usize, height as usize), ..Self::default() };
+    }
+    fn square(&mut self, x: isize, y: isize) {
+        self.shape.insert(x, y);
+    }
+    fn piece(&mut self, piece: ChessPiece, x: isize, y: isize) {
+        self.kinds.push((piece.kind, piece.color));
+        self.pieces.append(self.render.draw_piece(piece.kind, piece.color, x, y));
+    }
+    fn path(&mut self, from: (isize, isize), to: (isize, isize)) {
+        self.paths.append(self.render.draw_path(from.0, from.1, to.0, to.1));
+    }
+    fn point(&mut self, x: isize, y: isize) {
+        self.marks.append(self.render.draw_point(x, y));
+    }
+    fn step(&mut self, x: isize, y: isize, step: usize) {
+        self.marks.append(self.render.draw_step(x, y, step));
+    }
+    fn highlight(&mut self, highlight: &Highlight, color: &str) {
+        self.highlights.get_or_insert_with(Group::new).append(self.render.draw_highlight(highlight, color));
+    }
+    fn arrow(&mut self, arrow: &Arrow) {
+        self.arrows.push(arrow.clone());
+    }
+    fn castling(&mut self, rights: &CastlingRights) {
+        self.castling = Some(self.render.draw_castling(rights));
+    }
+    fn side_to_move(&mut self, color: PieceColor) {
+        self.side_to_move = Some(color);
+    }
+    fn finish(self) -> Document {
+        let (width, height) = (self.shape.width() as isize, self.shape.height() as isize);
+        let mut document = self.render.document(width as f32, height as f32);
+        if self.side_to_move.is_some() {
+            // room for the disc right of the board
+            let (x, y, w, h) = self.render.view_box(width as f32, height as f32);
+            document = document.set("viewBox", (x, y, w + self.render.grid_size / 2.0, h));
+        }
+        if self.render.compact && !self.render.shared_definitions {
+            document = document.add(self.render.draw_definitions(self.kinds));
+        }
+        let board = if self.render.compact && self.shape.is_rectangle() {
+            self.render.draw_checkerboard(width, height)
+        }
+        else {
+            self.render.draw_board(&self.shape)
+        };
+        document = document.add(board).add(self.render.draw_coordinates(width, height));
+        if let Some(highlights) = self.highlights {
+            document = document.add(highlights);
+        }
+        if let Some(castling) = self.castling {
+            document = document.add(castling);
+        }
+        document = document.add(self.paths).add(self.marks).add(self.pieces);
+        if !self.arrows.is_empty() {
+            document = document.add(self.render.draw_arrows(&self.arrows));
+        }
+        if let Some(color) = self.side_to_move {
+            document = document.add(self.render.draw_side_to_move(color));
+        }
+        document
+    }
+}
+
+impl ChessRender {
+    /// Draw the squares and pieces of a position through any backend
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessman::{AsciiBackend, ChessPosition, ChessRender};
+    /// let text = ChessRender::default()
+    ///     .render_position_with(&ChessPosition::starting(), AsciiBackend::default());
+    /// assert!(text.starts_with("r n b q k b n r"));
+    /// ```
+    pub fn render_position_with<B: RenderBackend>(&self, position: &ChessPosition, backend: B) -> B::Output {
+        self.render_annotated_with(position, &Annotations::default(), backend)
+    }
+    /// Draw a position with its highlights, arrows and the enabled indicators through any backend
+    pub fn render_annotated_with<B: RenderBackend>(
+        &self,
+        position: &ChessPosition,
+        annotations: &Annotations,
+        mut backend: B,
+    ) -> B::Output {
+        backend.begin(self, 8, 8);
+        for (x, y) in BoardShape::rectangle(8, 8).squares() {
+            backend.square(x, y);
+        }
+        for (layer, highlights) in annotations.layers() {
+            for highlight in highlights {
+                backend.highlight(highlight, highlight.color.as_deref().unwrap_or_else(|| self.layer_color(layer)));
+            }
+        }
+        if self.show_castling {
+            backend.castling(&position.castling);
+        }
+        for (x, y, piece) in position.pieces() {
+            backend.piece(piece, x, y);
+        }
+        for arrow in &annotations.arrows {
+            backend.arrow(arrow);
+        }
+        if self.show_side_to_move {
+            backend.side_to_move(position.side_to_move);
+        }
+        backend.finish()
+    }
+    /// Draw an empty board of any shape through any backend
+    pub fn render_shape_with<B: RenderBackend>(&self, shape: &BoardShape, backend: B) -> B::Output {
+        self.render_board_with(shape, std::iter::empty(), backend)
+    }
+    /// Draw a board of any shape with pieces on it through any backend
+    pub fn render_board_with<B: RenderBackend>(
+        &self,
+        shape: &BoardShape,
+        pieces: impl IntoIterator<Item = (isize, isize, ChessPiece)>,
+        mut backend: B,
+    ) -> B::Output {
+        backend.begin(self, shape.width() as isize, shape.height() as isize);
+        for (x, y) in shape.squares() {
+            backend.square(x, y);
+        }
+        for (x, y, piece) in pieces {
+            backend.piece(piece, x, y);
+        }
+        backend.finish()
+    }
+    /// Draw a knight's tour with its path and numbered steps through any backend
+    pub fn render_tour_with<B: RenderBackend>(&self, tour: &KnightTour, mut backend: B) -> B::Output {
+        backend.begin(self, tour.shape.width() as isize, tour.shape.height() as isize);
+        for (x, y) in tour.shape.squares() {
+            backend.square(x, y);
+        }
+        for pair in tour.steps.windows(2) {
+            backend.path(pair[0], pair[1]);
+        }
+        if let (true, Some(&last), Some(&first)) = (tour.closed, tour.steps.last(), tour.steps.first()) {
+            backend.path(last, first);
+        }
+        for (step, &(x, y)) in tour.steps.iter().enumerate() {
+            backend.point(x, y);
+            backend.step(x, y, step + 1);
+        }
+        backend.finish()
+    }
+}
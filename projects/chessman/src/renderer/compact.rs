@@ -0,0 +1,110 @@
+use super::*;
+use std::collections::BTreeSet;
+use svg::node::element::{Definitions, Pattern, Use};
+
+impl ChessRender {
+    /// Draw the squares of a `width` × `height` board
+    ///
+    /// In [`compact`](Self::compact) mode this is one rectangle filled with the checkerboard pattern
+    /// from [`draw_definitions`](Self::draw_definitions), otherwise one rectangle per square.
+    pub fn draw_checkerboard(&self, width: isize, height: isize) -> Group {
+        let mut group = Group::new();
+        if self.compact && self.geometry == Geometry::Square {
+            let (left, top, right, bottom) = self.board_bounds(width as f32, height as f32);
+            return group.add(
+                Rectangle::new()
+                    .set("x", left)
+                    .set("y", top)
+                    .set("width", right - left)
+                    .set("height", bottom - top)
+                    .set("fill", format!("url(#{})", self.checkerboard_id())),
+            );
+        }
+        for y in 0..height {
+            for x in 0..width {
+                group = group.add(self.draw_square(x, y));
+            }
+        }
+        group
+    }
+    /// The checkerboard pattern and the glyphs of `pieces`, referenced by compact boards
+    ///
+    /// Ids are derived from the colors, so boards sharing a page share their definitions.
+    pub fn draw_definitions(&self, pieces: impl IntoIterator<Item = (PieceKind, PieceColor)>) -> Definitions {
+        let mut definitions = Definitions::new().add(self.checkerboard_pattern());
+        for (kind, color) in pieces.into_iter().collect::<BTreeSet<_>>() {
+            definitions = definitions.add(self.draw_glyph(kind, color).set("id", self.piece_id(kind, color)));
+        }
+        definitions
+    }
+    /// Everything boards drawn with [`shared_definitions`](Self::shared_definitions) refer to, for the page holding them
+    ///
+    /// In compact mode that is the checkerboard pattern and the glyphs of all pieces, and always the
+    /// arrowheads of the theme arrow color and of `arrow_colors`.
+    pub fn draw_shared_definitions(&self, arrow_colors: &[String]) -> Definitions {
+        let mut definitions = Definitions::new();
+        if self.compact {
+            let colors = [PieceColor::White, PieceColor::Black];
+            let pieces = PieceKind::ALL.iter().flat_map(|&kind| colors.iter().map(move |&color| (kind, color)));
+            definitions = self.draw_definitions(pieces);
+        }
+        let mut markers = BTreeSet::new();
+        markers.insert(self.theme.arrow_color.as_str());
+        markers.extend(arrow_colors.iter().map(String::as_str));
+        for color in markers {
+            definitions = definitions.add(self.arrow_marker(color));
+        }
+        definitions
+    }
+    /// Two by two squares tiling the screen, aligned to the grid
+    fn checkerboard_pattern(&self) -> Pattern {
+        let size = self.grid_size;
+        let (even, odd) = self.checker_colors();
+        let square = |x: f32, y: f32, side: f32, color: &str| {
+            Rectangle::new().set("x", x).set("y", y).set("width", side).set("height", side).set("fill", color.to_string())
+        };
+        Pattern::new()
+            .set("id", self.checkerboard_id())
+            .set("patternUnits", "userSpaceOnUse")
+            .set("width", 2.0 * size)
+            .set("height", 2.0 * size)
+            .add(square(0.0, 0.0, 2.0 * size, even))
+            .add(square(size, 0.0, size, odd))
+            .add(square(0.0, size, size, odd))
+    }
+    /// Colors of the squares at even and odd screen positions
+    fn checker_colors(&self) -> (&str, &str) {
+        // turning by 90° swaps the colors of the squares on even screen positions
+        if self.orientation.is_rotated() {
+            (self.grid_color(1, 0), self.grid_color(0, 0))
+        }
+        else {
+            (self.grid_color(0, 0), self.grid_color(1, 0))
+        }
+    }
+    fn checkerboard_id(&self) -> String {
+        let (even, odd) = self.checker_colors();
+        format!("checkerboard-{}-{}-{}", id_name(even), id_name(odd), self.grid_size)
+    }
+    fn piece_id(&self, kind: PieceKind, color: PieceColor) -> String {
+        let set = match self.theme.piece_set {
+            PieceSet::Classic => "classic",
+            PieceSet::Letters => "letters",
+        };
+        let letter = ChessPiece::new(kind, color).fen_char();
+        format!("piece-{}-{}-{}-{}", set, letter, id_name(&self.theme.piece_white), id_name(&self.theme.piece_black))
+    }
+    /// A reference to the glyph of a piece defined by [`draw_definitions`](Self::draw_definitions)
+    pub(super) fn use_glyph(&self, kind: PieceKind, color: PieceColor) -> Use {
+        Use::new().set("href", format!("#{}", self.piece_id(kind, color)))
+    }
+    /// Round a coordinate to hundredths in compact mode
+    pub(super) fn round(&self, value: f32) -> f32 {
+        if self.compact {
+            (value * 100.0).round() / 100.0
+        }
+        else {
+            value
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use super::*;
+
+/// Where file and rank labels are drawn
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CoordinateStyle {
+    /// No labels
+    #[default]
+    Hidden,
+    /// Labels in a margin around the board
+    Outside,
+    /// Labels in the corners of the edge squares
+    Inside,
+}
+
+/// Label of the file `x`: `a` to `z`, then `aa`, `ab` and so on
+pub fn file_label(x: usize) -> String {
+    let mut label = vec![];
+    let mut x = x + 1;
+    while x > 0 {
+        x -= 1;
+        label.push(b'a' + (x % 26) as u8);
+        x /= 26;
+    }
+    label.iter().rev().map(|c| *c as char).collect()
+}
+
+/// Label of the row `y` on a board `height` squares high, counting from 1 at the bottom
+pub fn rank_label(y: usize, height: usize) -> String {
+    (height - y).to_string()
+}
+
+impl ChessRender {
+    /// Width of the label margin around the board
+    pub fn margin(&self) -> f32 {
+        match self.coordinates {
+            CoordinateStyle::Outside => self.label_margin * self.grid_size,
+            _ => 0.0,
+        }
+    }
+    /// Draw file and rank labels along the bottom and left screen edges of a `width` × `height` board
+    ///
+    /// The labels follow the [`Orientation`], so a flipped board reads `h` to `a` and a
+    /// rotated board gets its rank numbers along the bottom.
+    pub fn draw_coordinates(&self, width: isize, height: isize) -> Group {
+        let mut group = Group::new().set("font-family", self.theme.label_font.as_str()).set("text-anchor", "middle");
+        if self.coordinates == CoordinateStyle::Hidden || width <= 0 || height <= 0 {
+            return group;
+        }
+        let rotated = self.orientation.is_rotated();
+        // label text, the edge square it belongs to, and whether it sits on the bottom edge
+        let mut labels = Vec::with_capacity((width + height) as usize);
+        for x in 0..width {
+            labels.push((file_label(x as usize), self.outer_square((x, 0), (x, height - 1), !rotated), !rotated));
+        }
+        for y in 0..height {
+            labels.push((rank_label(y as usize, height as usize), self.outer_square((0, y), (width - 1, y), rotated), rotated));
+        }
+        let half = self.grid_size / 2.0;
+        match self.coordinates {
+            CoordinateStyle::Hidden => {}
+            CoordinateStyle::Outside => {
+                let font_size = self.grid_size * 0.3;
+                let margin = self.margin();
+                let (left, _, _, bottom) = self.board_bounds(width as f32, height as f32);
+                group = group.set("font-size", font_size).set("fill", self.theme.label_color.as_str());
+                for (label, (x, y), on_bottom) in labels {
+                    let (cx, cy) = self.square_center(x, y);
+                    group = group.add(if on_bottom {
+                        self.draw_label(label, cx, bottom + (margin + font_size * 0.7) / 2.0)
+                    }
+                    else {
+                        self.draw_label(label, left - margin / 2.0, cy + font_size * 0.35)
+                    });
+                }
+            }
+            CoordinateStyle::Inside => {
+                let font_size = self.grid_size * 0.22;
+                let padding = self.grid_size * 0.06;
+                group = group.set("font-size", font_size).set("font-weight", "bold");
+                for (label, (x, y), on_bottom) in labels {
+                    let (cx, cy) = self.square_center(x, y);
+                    let text = if on_bottom {
+                        self.draw_label(label, cx + half - padding - font_size * 0.3, cy + half - padding)
+                    }
+                    else {
+                        self.draw_label(label, cx - half + padding + font_size * 0.3, cy - half + padding + font_size * 0.75)
+                    };
+                    // the color of the neighbouring squares stands out on this one
+                    group = group.add(text.set("fill", self.grid_color(x + 1, y)));
+                }
+            }
+        }
+        group
+    }
+    /// Of two edge squares, the one shown at the bottom or the one shown on the left
+    fn outer_square(&self, a: (isize, isize), b: (isize, isize), bottom: bool) -> (isize, isize) {
+        let (ax, ay) = self.square_center(a.0, a.1);
+        let (bx, by) = self.square_center(b.0, b.1);
+        let a_wins = if bottom { ay >= by } else { ax <= bx };
+        if a_wins {
+            a
+        }
+        else {
+            b
+        }
+    }
+    pub(super) fn draw_label(&self, label: String, x: f32, y: f32) -> Text {
+        Text::new().set("x", x).set("y", y).add(svg::node::Text::new(label))
+    }
+}
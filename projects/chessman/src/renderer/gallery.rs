@@ -0,0 +1,119 @@
+use super::*;
+
+/// Many rendered boards tiled into sheets, like all solutions of a puzzle on a worksheet
+#[derive(Clone, Debug)]
+pub struct Gallery {
+    /// Boards in every row
+    pub columns: usize,
+    /// Width of the cell every board is fitted into
+    pub tile_width: f32,
+    /// Height of the cell every board is fitted into, captions go below it
+    pub tile_height: f32,
+    /// Gap between the tiles and around the sheet
+    pub spacing: f32,
+    /// Font size of the captions
+    pub caption_size: f32,
+    /// Most boards on one sheet before a new one is started, `None` to keep them all on one
+    pub page_limit: Option<usize>,
+    /// Colors of the arrows on the boards besides the theme arrow color, whose arrowheads every sheet defines
+    pub arrow_colors: Vec<String>,
+    tiles: Vec<(Document, Option<String>)>,
+}
+
+impl Default for Gallery {
+    fn default() -> Self {
+        Self {
+            columns: 4,
+            tile_width: 200.0,
+            tile_height: 200.0,
+            spacing: 20.0,
+            caption_size: 16.0,
+            page_limit: None,
+            arrow_colors: vec![],
+            tiles: vec![],
+        }
+    }
+}
+
+impl Gallery {
+    /// An empty gallery with `columns` boards in a row
+    pub fn new(columns: usize) -> Self {
+        Self { columns, ..Self::default() }
+    }
+    /// Add a board without caption
+    pub fn push(&mut self, board: Document) -> &mut Self {
+        self.tiles.push((board, None));
+        self
+    }
+    /// Add a board with a caption below it
+    pub fn push_captioned(&mut self, board: Document, caption: impl Into<String>) -> &mut Self {
+        self.tiles.push((board, Some(caption.into())));
+        self
+    }
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+    /// Number of sheets the boards fill
+    pub fn page_count(&self) -> usize {
+        match self.page_limit {
+            Some(limit) => self.tiles.len().div_ceil(limit.max(1)),
+            None => usize::from(!self.tiles.is_empty()),
+        }
+    }
+    /// Size of a sheet holding `count` boards
+    pub fn page_size(&self, count: usize, captioned: bool) -> (f32, f32) {
+        let columns = self.columns.max(1);
+        let rows = count.div_ceil(columns);
+        let width = columns as f32 * (self.tile_width + self.spacing) + self.spacing;
+        let height = rows as f32 * (self.tile_height + self.caption_height(captioned) + self.spacing) + self.spacing;
+        (width, height)
+    }
+    fn caption_height(&self, captioned: bool) -> f32 {
+        if captioned {
+            self.caption_size * 1.5
+        }
+        else {
+            0.0
+        }
+    }
+}
+
+impl ChessRender {
+    /// Lay the boards of a gallery out in rows, one document for every sheet
+    ///
+    /// Boards rendered with [`shared_definitions`](Self::shared_definitions) by a renderer like this one
+    /// refer to the definitions every sheet holds once.
+    pub fn render_gallery(&self, gallery: &Gallery) -> Vec<Document> {
+        let limit = gallery.page_limit.unwrap_or(usize::MAX).max(1);
+        gallery.tiles.chunks(limit).map(|tiles| self.draw_gallery_page(gallery, tiles)).collect()
+    }
+    fn draw_gallery_page(&self, gallery: &Gallery, tiles: &[(Document, Option<String>)]) -> Document {
+        let captioned = tiles.iter().any(|(_, caption)| caption.is_some());
+        let (width, height) = gallery.page_size(tiles.len(), captioned);
+        let row_height = gallery.tile_height + gallery.caption_height(captioned) + gallery.spacing;
+        let columns = gallery.columns.max(1);
+        let mut document = Document::new().set("viewBox", (0.0, 0.0, width, height));
+        if self.shared_definitions {
+            document = document.add(self.draw_shared_definitions(&gallery.arrow_colors));
+        }
+        let mut captions = Group::new()
+            .set("font-family", self.theme.label_font.as_str())
+            .set("font-size", gallery.caption_size)
+            .set("text-anchor", "middle")
+            .set("fill", self.theme.label_color.as_str());
+        for (i, (board, caption)) in tiles.iter().enumerate() {
+            let x = gallery.spacing + (i % columns) as f32 * (gallery.tile_width + gallery.spacing);
+            let y = gallery.spacing + (i / columns) as f32 * row_height;
+            document = document
+                .add(board.clone().set("x", x).set("y", y).set("width", gallery.tile_width).set("height", gallery.tile_height));
+            if let Some(caption) = caption {
+                let baseline = y + gallery.tile_height + gallery.caption_size * 1.2;
+                captions = captions.add(self.draw_label(caption.clone(), x + gallery.tile_width / 2.0, baseline));
+            }
+        }
+        document.add(captions)
+    }
+}
@@ -0,0 +1,63 @@
+use super::*;
+use crate::{AttackHeatmap, SquareStatus};
+
+impl ChessRender {
+    /// Render a board with a heatmap over its squares and the pieces on top
+    pub fn render_heatmap(&self, heatmap: &AttackHeatmap) -> Document {
+        let (width, height) = (heatmap.width() as isize, heatmap.height() as isize);
+        let mut document = self.document(width as f32, height as f32);
+        if self.compact && !self.shared_definitions {
+            document =
+                document.add(self.draw_definitions(heatmap.pieces().iter().map(|(_, _, piece)| (piece.kind, piece.color))));
+        }
+        document = document
+            .add(self.draw_checkerboard(width, height))
+            .add(self.draw_coordinates(width, height))
+            .add(self.draw_heatmap(heatmap));
+        for &(x, y, piece) in heatmap.pieces() {
+            document = document.add(self.draw_piece(piece.kind, piece.color, x, y));
+        }
+        document
+    }
+    /// Shade every square by its [`SquareStatus`]
+    ///
+    /// Attacked squares get darker the more pieces attack them and show the number of attackers,
+    /// safe squares are tinted, contested squares are filled with the check color.
+    pub fn draw_heatmap(&self, heatmap: &AttackHeatmap) -> Group {
+        let max = heatmap.max_attacks().max(1) as f32;
+        let font_size = self.grid_size * 0.22;
+        let mut shades = Group::new();
+        let mut counts = Group::new()
+            .set("font-family", self.theme.label_font.as_str())
+            .set("font-size", font_size)
+            .set("font-weight", "bold")
+            .set("text-anchor", "end")
+            .set("fill", self.theme.label_color.as_str());
+        for y in 0..heatmap.height() as isize {
+            for x in 0..heatmap.width() as isize {
+                let attacks = heatmap.attacks(x, y);
+                let (color, opacity) = match heatmap.status(x, y) {
+                    SquareStatus::Safe => (self.theme.safe_color.as_str(), 0.35),
+                    SquareStatus::Attacked => {
+                        let opacity = 0.15 + 0.55 * attacks as f32 / max;
+                        (self.theme.heat_color.as_str(), (opacity * 100.0).round() / 100.0)
+                    }
+                    SquareStatus::Occupied => continue,
+                    SquareStatus::Contested => (self.theme.check_color.as_str(), 0.7),
+                };
+                shades = shades.add(self.draw_square(x, y).set("fill", color).set("fill-opacity", opacity));
+                if attacks > 0 {
+                    let (cx, cy) = self.square_center(x, y);
+                    let half = self.grid_size / 2.0;
+                    counts = counts.add(
+                        Text::new()
+                            .set("x", cx + half - font_size * 0.3)
+                            .set("y", cy - half + font_size)
+                            .add(svg::node::Text::new(attacks.to_string())),
+                    );
+                }
+            }
+        }
+        Group::new().add(shades).add(counts)
+    }
+}
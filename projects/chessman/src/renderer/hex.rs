@@ -0,0 +1,123 @@
+use super::*;
+use crate::{Annotations, HexBoard};
+use std::collections::BTreeMap;
+
+/// The grid cells are laid out on
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Geometry {
+    /// Square cells, `(x, y)` counts files and rows from the top left
+    #[default]
+    Square,
+    /// Flat-topped hexagons in the axial coordinates of [`HexBoard`]
+    Hex,
+}
+
+/// Circumradius of a hexagon one grid unit high, in grid units
+const HEX_RADIUS: f32 = 0.577_350_3;
+
+impl ChessRender {
+    /// A renderer for hexagonal boards
+    pub fn hex() -> Self {
+        Self { geometry: Geometry::Hex, ..Self::default() }
+    }
+    /// Screen position of a point in fractional axial coordinates
+    pub fn hex_position(&self, q: f32, r: f32) -> (f32, f32) {
+        self.screen_position(1.5 * HEX_RADIUS * q, r + q / 2.0)
+    }
+    /// Screen positions of the six corners of cell `(q, r)`, clockwise from the right
+    pub fn hex_corners(&self, q: isize, r: isize) -> Vec<(f32, f32)> {
+        let (x, y) = (1.5 * HEX_RADIUS * q as f32, r as f32 + q as f32 / 2.0);
+        let corners = [(1.0, 0.0), (0.5, 0.5), (-0.5, 0.5), (-1.0, 0.0), (-0.5, -0.5), (0.5, -0.5)];
+        corners.iter().map(|&(dx, dy)| self.screen_position(x + dx * HEX_RADIUS, y + dy)).collect()
+    }
+    /// The light, middle or dark cell color
+    pub fn hex_color(&self, q: isize, r: isize) -> &str {
+        match HexBoard::cell_shade(q, r) {
+            0 => self.theme.board_white.as_str(),
+            1 => self.theme.board_middle.as_str(),
+            _ => self.theme.board_black.as_str(),
+        }
+    }
+    /// Draw the hexagonal cell `(q, r)` in one of the three shades
+    pub fn draw_cell(&self, q: isize, r: isize) -> Path {
+        Path::new().set("d", self.hex_data(q, r)).set("fill", self.hex_color(q, r))
+    }
+    pub(super) fn hex_data(&self, q: isize, r: isize) -> Data {
+        let corners = self.hex_corners(q, r);
+        let mut data = Data::new().move_to(corners[0]);
+        for &corner in &corners[1..] {
+            data = data.line_to(corner);
+        }
+        data.close()
+    }
+    /// The `viewBox` of a hexagonal board and its label margin
+    pub fn hex_view_box(&self, board: &HexBoard) -> (f32, f32, f32, f32) {
+        let (mut left, mut top, mut right, mut bottom) = (f32::MAX, f32::MAX, f32::MIN, f32::MIN);
+        for (q, r) in board.cells() {
+            for (x, y) in self.hex_corners(q, r) {
+                left = left.min(x);
+                top = top.min(y);
+                right = right.max(x);
+                bottom = bottom.max(y);
+            }
+        }
+        if left > right {
+            return (0.0, 0.0, 0.0, 0.0);
+        }
+        let margin = self.margin();
+        (left - margin, top - margin, right - left + 2.0 * margin, bottom - top + 2.0 * margin)
+    }
+    /// Render a hexagonal board and its pieces
+    pub fn render_hex(&self, board: &HexBoard) -> Document {
+        self.render_hex_annotated(board, &Annotations::default())
+    }
+    /// Render a hexagonal board with highlights and arrows, cells take the place of squares
+    pub fn render_hex_annotated(&self, board: &HexBoard, annotations: &Annotations) -> Document {
+        let render = Self { geometry: Geometry::Hex, ..self.clone() };
+        let mut document = Document::new().set("viewBox", render.hex_view_box(board));
+        if render.compact && !render.shared_definitions {
+            document = document.add(render.draw_definitions(board.pieces().map(|(_, _, piece)| (piece.kind, piece.color))));
+        }
+        for (q, r) in board.cells() {
+            document = document.add(render.draw_cell(q, r));
+        }
+        document = document.add(render.draw_hex_coordinates(board));
+        document = document.add(render.draw_annotations(annotations));
+        for (q, r, piece) in board.pieces() {
+            document = document.add(render.draw_piece(piece.kind, piece.color, q, r));
+        }
+        if !annotations.arrows.is_empty() {
+            document = document.add(render.draw_arrows(&annotations.arrows));
+        }
+        document
+    }
+    /// Draw file letters below the lowest cell of every file, and rank numbers left of the leftmost cell of every rank
+    pub fn draw_hex_coordinates(&self, board: &HexBoard) -> Group {
+        let mut group = Group::new().set("font-family", self.theme.label_font.as_str()).set("text-anchor", "middle");
+        let (font_size, offset) = match self.coordinates {
+            CoordinateStyle::Hidden => return group,
+            CoordinateStyle::Outside => (self.grid_size * 0.3, 0.8),
+            CoordinateStyle::Inside => (self.grid_size * 0.22, 0.3),
+        };
+        group = group.set("font-size", font_size).set("fill", self.theme.label_color.as_str());
+        // the label of every cell split into file and rank, keeping the cell with the first file of each rank
+        let mut files: BTreeMap<String, (isize, isize)> = BTreeMap::new();
+        let mut ranks: BTreeMap<usize, (isize, isize)> = BTreeMap::new();
+        for (q, r) in board.cells() {
+            let name = board.cell_name(q, r).unwrap_or_default();
+            let (file, rank) = name.split_at(name.find(|c: char| c.is_ascii_digit()).unwrap_or(name.len()));
+            let lowest = files.entry(file.to_string()).or_insert((q, r));
+            lowest.1 = lowest.1.max(r);
+            ranks.entry(rank.parse().unwrap_or(0)).or_insert((q, r));
+        }
+        for (file, (q, r)) in files {
+            let (x, y) = self.hex_position(q as f32, r as f32 + offset);
+            group = group.add(self.draw_label(file, x, y + font_size * 0.35));
+        }
+        for (rank, (q, r)) in ranks {
+            let (x, y) = self.hex_position(q as f32 - offset, r as f32 + offset / 2.0);
+            group = group.add(self.draw_label(rank.to_string(), x, y + font_size * 0.35));
+        }
+        group
+    }
+}
@@ -0,0 +1,80 @@
+use super::*;
+use crate::{Annotations, Highlight, HighlightLayer, HighlightStyle};
+
+impl ChessRender {
+    /// The color of highlights on a layer
+    pub fn layer_color(&self, layer: &HighlightLayer) -> &str {
+        match layer {
+            HighlightLayer::LastMove => self.theme.last_move_color.as_str(),
+            HighlightLayer::Selection => self.theme.selection_color.as_str(),
+            HighlightLayer::Check => self.theme.check_color.as_str(),
+            HighlightLayer::Custom(_) => self.theme.highlight_color.as_str(),
+        }
+    }
+    /// Draw one highlight in the given color
+    pub fn draw_highlight(&self, highlight: &Highlight, color: &str) -> Path {
+        let (cx, cy) = self.square_center(highlight.x, highlight.y);
+        let half = self.grid_size / 2.0;
+        let path = Path::new();
+        match highlight.style {
+            HighlightStyle::Fill if self.geometry == Geometry::Hex => {
+                path.set("d", self.hex_data(highlight.x, highlight.y)).set("fill", color)
+            }
+            HighlightStyle::Corner if self.geometry == Geometry::Hex => {
+                let corners = self.hex_corners(highlight.x, highlight.y);
+                let mut data = Data::new();
+                for (i, &(x, y)) in corners.iter().enumerate() {
+                    let along = |(nx, ny): (f32, f32)| (x + (nx - x) * 0.3, y + (ny - y) * 0.3);
+                    data =
+                        data.move_to((x, y)).line_to(along(corners[(i + 5) % 6])).line_to(along(corners[(i + 1) % 6])).close();
+                }
+                path.set("d", data).set("fill", color)
+            }
+            HighlightStyle::Fill => {
+                let data = Data::new()
+                    .move_to((cx - half, cy - half))
+                    .horizontal_line_by(self.grid_size)
+                    .vertical_line_by(self.grid_size)
+                    .horizontal_line_by(-self.grid_size)
+                    .close();
+                path.set("d", data).set("fill", color)
+            }
+            HighlightStyle::Ring => {
+                let width = self.grid_size / 12.0;
+                path.set("d", circle_data(cx, cy, half - width / 2.0))
+                    .set("fill", "none")
+                    .set("stroke", color)
+                    .set("stroke-width", width)
+            }
+            HighlightStyle::Corner => {
+                let size = self.grid_size / 4.0;
+                let mut data = Data::new();
+                for &(sx, sy) in [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)].iter() {
+                    let (x, y) = (cx + sx * half, cy + sy * half);
+                    data = data.move_to((x, y)).line_by((-sx * size, 0.0)).line_to((x, y - sy * size)).close();
+                }
+                path.set("d", data).set("fill", color)
+            }
+            HighlightStyle::Dot => path.set("d", circle_data(cx, cy, self.grid_size / 8.0)).set("fill", color),
+        }
+    }
+    /// Draw all highlight layers, in their fixed order
+    pub fn draw_annotations(&self, annotations: &Annotations) -> Group {
+        let mut group = Group::new();
+        for (layer, highlights) in annotations.layers() {
+            for highlight in highlights {
+                let color = highlight.color.as_deref().unwrap_or_else(|| self.layer_color(layer));
+                group = group.add(self.draw_highlight(highlight, color));
+            }
+        }
+        group
+    }
+}
+
+fn circle_data(cx: f32, cy: f32, r: f32) -> Data {
+    Data::new()
+        .move_to((cx + r, cy))
+        .elliptical_arc_to((r, r, 0, 1, 1, cx - r, cy))
+        .elliptical_arc_to((r, r, 0, 1, 1, cx + r, cy))
+        .close()
+}
@@ -0,0 +1,170 @@
+use super::*;
+use crate::{Annotations, ChessMove, ChessPosition, KnightTour, MoveError};
+
+/// Frames of a diagram exported as one HTML page, stepped through with buttons or the arrow keys
+///
+/// Every frame is inlined as svg in both orientations, the page loads nothing from the network.
+#[derive(Clone, Debug, Default)]
+pub struct HtmlViewer {
+    /// Heading and title of the page
+    pub title: String,
+    frames: Vec<HtmlFrame>,
+}
+
+#[derive(Clone, Debug)]
+struct HtmlFrame {
+    normal: Document,
+    flipped: Document,
+    caption: String,
+}
+
+const VIEWER_STYLE: &str = r#"body{font-family:sans-serif;margin:2em;text-align:center}
+.frame{display:none;margin:0}
+.frame.current{display:block}
+.frame svg{width:min(90vw,480px);height:auto}
+.flipped,body.flip .normal{display:none}
+body.flip .flipped{display:block}
+nav{margin-top:1em}
+nav button{font-size:1.2em;min-width:3em}
+#counter{display:inline-block;min-width:6em}"#;
+
+const VIEWER_CONTROLS: &str = r#"<nav>
+<button id="first" type="button">⏮</button>
+<button id="prev" type="button">◀</button>
+<span id="counter"></span>
+<button id="next" type="button">▶</button>
+<button id="last" type="button">⏭</button>
+<button id="flip" type="button">Flip</button>
+</nav>
+"#;
+
+const VIEWER_SCRIPT: &str = r#"const frames = document.querySelectorAll(".frame");
+let current = 0;
+function show(step) {
+    current = Math.max(0, Math.min(frames.length - 1, step));
+    frames.forEach((frame, i) => frame.classList.toggle("current", i === current));
+    document.getElementById("counter").textContent = (current + 1) + " / " + frames.length;
+}
+function flip() {
+    document.body.classList.toggle("flip");
+}
+document.getElementById("first").onclick = () => show(0);
+document.getElementById("prev").onclick = () => show(current - 1);
+document.getElementById("next").onclick = () => show(current + 1);
+document.getElementById("last").onclick = () => show(frames.length - 1);
+document.getElementById("flip").onclick = flip;
+document.addEventListener("keydown", (event) => {
+    switch (event.key) {
+        case "ArrowLeft": show(current - 1); break;
+        case "ArrowRight": show(current + 1); break;
+        case "Home": show(0); break;
+        case "End": show(frames.length - 1); break;
+        case "f": flip(); break;
+    }
+});
+show(0);"#;
+
+impl HtmlViewer {
+    /// An empty viewer with the given page title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { title: title.into(), frames: vec![] }
+    }
+    /// Add a frame, drawn once as seen normally and once with the board flipped
+    pub fn push(&mut self, normal: Document, flipped: Document, caption: impl Into<String>) -> &mut Self {
+        self.frames.push(HtmlFrame { normal, flipped, caption: caption.into() });
+        self
+    }
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+    /// The complete page, with styles and script inline
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!(
+            "<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n",
+            escape_html(&self.title),
+            VIEWER_STYLE
+        ));
+        html.push_str(&format!("<h1>{}</h1>\n<main>\n", escape_html(&self.title)));
+        for frame in &self.frames {
+            html.push_str(&format!(
+                "<figure class=\"frame\">\n<div class=\"normal\">\n{}\n</div>\n<div class=\"flipped\">\n{}\n</div>\n<figcaption>{}</figcaption>\n</figure>\n",
+                frame.normal,
+                frame.flipped,
+                escape_html(&frame.caption)
+            ));
+        }
+        html.push_str("</main>\n");
+        html.push_str(VIEWER_CONTROLS);
+        html.push_str(&format!("<script>\n{}\n</script>\n</body>\n</html>\n", VIEWER_SCRIPT));
+        html
+    }
+}
+
+impl ChessRender {
+    /// A viewer of `count` frames, `frame` draws the `i`-th one with the given renderer and names it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessman::ChessRender;
+    /// let fens = ["8/8/8/4k3/8/8/8/4K3 w - - 0 1", "8/8/8/4k3/8/8/4K3/8 b - - 1 1"];
+    /// let viewer = ChessRender::default().html_viewer("King walk", fens.len(), |render, i| {
+    ///     (render.render_fen(fens[i]).unwrap(), fens[i].to_string())
+    /// });
+    /// assert!(viewer.to_html().contains("<title>King walk</title>"));
+    /// ```
+    pub fn html_viewer<F>(&self, title: &str, count: usize, mut frame: F) -> HtmlViewer
+    where
+        F: FnMut(&ChessRender, usize) -> (Document, String),
+    {
+        let flipped = Self { orientation: self.orientation.flipped(), ..self.clone() };
+        let mut viewer = HtmlViewer::new(title);
+        for i in 0..count {
+            let (normal, caption) = frame(self, i);
+            let (other, _) = frame(&flipped, i);
+            viewer.push(normal, other, caption);
+        }
+        viewer
+    }
+    /// A viewer of a game, the start position then one frame after every move with the move highlighted
+    pub fn html_moves(&self, title: &str, position: &ChessPosition, moves: &[ChessMove]) -> Result<HtmlViewer, MoveError> {
+        let mut positions = vec![position.clone()];
+        for chess_move in moves {
+            let mut next = positions[positions.len() - 1].clone();
+            next.play(chess_move)?;
+            positions.push(next);
+        }
+        Ok(self.html_viewer(title, positions.len(), |render, i| {
+            let mut annotations = Annotations::default();
+            let caption = match i.checked_sub(1).map(|ply| &moves[ply]) {
+                Some(chess_move) => {
+                    annotations.last_move(chess_move.from, chess_move.to);
+                    let before = &positions[i - 1];
+                    let dots = if before.side_to_move == PieceColor::White { "." } else { "..." };
+                    format!("{}{} {}", before.fullmove_number, dots, chess_move)
+                }
+                None => "Start".to_string(),
+            };
+            (render.render_annotated(&positions[i], &annotations), caption)
+        }))
+    }
+    /// A viewer of a knight's tour growing one step per frame
+    pub fn html_tour(&self, title: &str, tour: &KnightTour) -> HtmlViewer {
+        self.html_viewer(title, tour.steps.len(), |render, i| {
+            let partial = KnightTour {
+                shape: tour.shape.clone(),
+                steps: tour.steps[..=i].to_vec(),
+                closed: tour.closed && i + 1 == tour.steps.len(),
+            };
+            (render.render_tour(&partial), format!("Step {}", i + 1))
+        })
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
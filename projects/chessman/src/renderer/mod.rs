@@ -1,70 +1,205 @@
+use self::arrows::id_name;
+use crate::{
+    pieces::{piece_glyph, GlyphPart, PieceColor, PieceKind, GLYPH_SIZE},
+    ChessPiece, PieceSet, Theme,
+};
 use svg::{
-    node::element::{Circle, Line, Rectangle, Text},
+    node::element::{path::Data, Circle, Group, Line, Path, Rectangle, Text},
     Document,
 };
 
+mod animation;
+mod arrows;
+mod backend;
+mod compact;
+mod coordinates;
+mod gallery;
+mod heatmap;
+mod hex;
+mod highlights;
+mod html;
+mod orientation;
+mod position;
+mod shape;
+mod shogi;
+mod text;
+mod tour;
+mod xiangqi;
+
+pub use self::{
+    animation::AnimationTiming,
+    arrows::{arrow_marker_id, Arrow},
+    backend::{RenderBackend, SvgBackend},
+    coordinates::{file_label, rank_label, CoordinateStyle},
+    gallery::Gallery,
+    hex::Geometry,
+    html::HtmlViewer,
+    orientation::Orientation,
+    text::{AsciiBackend, TerminalBackend},
+};
+
+#[derive(Clone, Debug)]
 pub struct ChessRender {
     pub grid_size: f32,
-    pub board_white: String,
-    pub board_black: String,
-    pub path_color: String,
+    /// Colors, fonts and pieces
+    pub theme: Theme,
     pub path_width: f32,
+    pub arrow_width: f32,
+    /// Draw a disc beside the board marking the side to move
+    pub show_side_to_move: bool,
+    /// Mark the corners of rooks that can still castle
+    pub show_castling: bool,
+    /// Where to draw file and rank labels
+    pub coordinates: CoordinateStyle,
+    /// Width of the outside label margin, in squares
+    pub label_margin: f32,
+    /// How the board is turned on screen
+    pub orientation: Orientation,
+    /// Width of the outline traced around shaped boards, `0` to leave it out
+    pub border_width: f32,
+    /// Square or hexagonal cells
+    pub geometry: Geometry,
+    /// Fill square boards with a pattern, draw chess pieces as references to shared glyphs and round coordinates,
+    /// for much smaller documents
+    pub compact: bool,
+    /// Leave the `<defs>` out of rendered boards, for pages that define them once with
+    /// [`draw_shared_definitions`](Self::draw_shared_definitions), like gallery sheets
+    pub shared_definitions: bool,
 }
 
 impl Default for ChessRender {
     fn default() -> Self {
         Self {
             grid_size: 100.0,
-            board_white: "#FFCF9E".to_string(),
-            board_black: "#D18A47".to_string(),
-            path_color: "#FF0000".to_string(),
+            theme: Theme::default(),
             path_width: 6.0,
+            arrow_width: 15.0,
+            show_side_to_move: false,
+            show_castling: false,
+            coordinates: CoordinateStyle::Hidden,
+            label_margin: 0.5,
+            orientation: Orientation::Normal,
+            border_width: 4.0,
+            geometry: Geometry::Square,
+            compact: false,
+            shared_definitions: false,
         }
     }
 }
 
 impl ChessRender {
+    /// A renderer using the given theme
+    pub fn with_theme(theme: Theme) -> Self {
+        Self { theme, ..Self::default() }
+    }
     pub fn document(&self, width: f32, height: f32) -> Document {
-        Document::new().set("viewBox", (0, 0, width * self.grid_size, height * self.grid_size))
+        Document::new().set("viewBox", self.view_box(width, height))
+    }
+    /// The `viewBox` of a `width` × `height` board and its label margin
+    pub fn view_box(&self, width: f32, height: f32) -> (f32, f32, f32, f32) {
+        let margin = self.margin();
+        let (left, top, right, bottom) = self.board_bounds(width, height);
+        let (x, y) = (self.round(left - margin), self.round(top - margin));
+        (x, y, self.round(right - left + 2.0 * margin), self.round(bottom - top + 2.0 * margin))
     }
     pub fn grid_color(&self, x: isize, y: isize) -> &str {
-        if (x + y) % 2 == 0 { self.board_white.as_str() } else { self.board_black.as_str() }
+        if (x + y) % 2 == 0 { self.theme.board_white.as_str() } else { self.theme.board_black.as_str() }
     }
     pub fn draw_square(&self, x: isize, y: isize) -> Rectangle {
+        let (cx, cy) = self.square_center(x, y);
         Rectangle::new()
-            .set("x", x as f32 * self.grid_size)
-            .set("y", y as f32 * self.grid_size)
+            .set("x", cx - self.grid_size / 2.0)
+            .set("y", cy - self.grid_size / 2.0)
             .set("width", self.grid_size)
             .set("height", self.grid_size)
             .set("fill", self.grid_color(x, y))
     }
     pub fn draw_path(&self, x1: isize, y1: isize, x2: isize, y2: isize) -> Line {
+        let (cx1, cy1) = self.square_center(x1, y1);
+        let (cx2, cy2) = self.square_center(x2, y2);
         Line::new()
-            .set("x1", self.center_position(x1))
-            .set("y1", self.center_position(y1))
-            .set("x2", self.center_position(x2))
-            .set("y2", self.center_position(y2))
-            .set("stroke", self.path_color.as_str())
-            .set("stroke-width", self.grid_size / 12.0)
+            .set("x1", cx1)
+            .set("y1", cy1)
+            .set("x2", cx2)
+            .set("y2", cy2)
+            .set("stroke", self.theme.path_color.as_str())
+            .set("stroke-width", self.path_width)
     }
     pub fn draw_point(&self, x: isize, y: isize) -> Circle {
-        Circle::new()
-            .set("cx", self.center_position(x))
-            .set("cy", self.center_position(y))
-            .set("r", self.grid_size / 6.0)
-            .set("fill", self.path_color.as_str())
+        let (cx, cy) = self.square_center(x, y);
+        Circle::new().set("cx", cx).set("cy", cy).set("r", self.grid_size / 6.0).set("fill", self.theme.path_color.as_str())
     }
 
     pub fn draw_step(&self, x: isize, y: isize, step: usize) -> Text {
+        let (cx, cy) = self.square_center(x, y);
         Text::new()
-            .set("x", self.center_position(x))
-            .set("y", self.center_position(y) + 12.0)
+            .set("x", cx)
+            .set("y", cy + 12.0)
             .set("text-anchor", "middle")
             .set("font-size", self.grid_size / 2.0)
             .set("fill", "#000000")
             .add(svg::node::Text::new(step.to_string()))
     }
-    fn center_position(&self, i: isize) -> f32 {
-        i as f32 * self.grid_size + self.grid_size / 2.0
+    /// Draw a chess piece on the square `(x, y)`, scaled to fit the grid
+    ///
+    /// In [`compact`](Self::compact) mode the piece refers to its glyph from [`draw_definitions`](Self::draw_definitions).
+    pub fn draw_piece(&self, kind: PieceKind, color: PieceColor, x: isize, y: isize) -> Group {
+        let (cx, cy) = self.square_center(x, y);
+        let transform = format!(
+            "translate({} {}) scale({})",
+            self.round(cx - self.grid_size / 2.0),
+            self.round(cy - self.grid_size / 2.0),
+            self.grid_size / GLYPH_SIZE
+        );
+        if self.compact {
+            return Group::new().set("transform", transform).add(self.use_glyph(kind, color));
+        }
+        self.draw_glyph(kind, color).set("transform", transform)
+    }
+    /// A chess piece in glyph units, `GLYPH_SIZE` wide
+    pub fn draw_glyph(&self, kind: PieceKind, color: PieceColor) -> Group {
+        let fill = self.piece_color(color);
+        let contrast = self.piece_color(color.opponent());
+        let mut group = Group::new()
+            .set("stroke", self.theme.piece_black.as_str())
+            .set("stroke-width", 1.5)
+            .set("stroke-linejoin", "round")
+            .set("stroke-linecap", "round");
+        match self.theme.piece_set {
+            PieceSet::Classic => {
+                for part in piece_glyph(kind) {
+                    group = match *part {
+                        GlyphPart::Body(data) => group.add(Path::new().set("d", data).set("fill", fill)),
+                        GlyphPart::Detail(data) => {
+                            group.add(Path::new().set("d", data).set("fill", "none").set("stroke", contrast))
+                        }
+                    };
+                }
+            }
+            PieceSet::Letters => {
+                let center = GLYPH_SIZE / 2.0;
+                let letter = kind.fen_char().to_ascii_uppercase().to_string();
+                group =
+                    group.add(Circle::new().set("cx", center).set("cy", center).set("r", center * 0.8).set("fill", fill)).add(
+                        Text::new()
+                            .set("x", center)
+                            .set("y", center + 7.0)
+                            .set("text-anchor", "middle")
+                            .set("font-family", self.theme.label_font.as_str())
+                            .set("font-size", 20)
+                            .set("font-weight", "bold")
+                            .set("stroke", "none")
+                            .set("fill", contrast)
+                            .add(svg::node::Text::new(letter)),
+                    );
+            }
+        }
+        group
+    }
+    fn piece_color(&self, color: PieceColor) -> &str {
+        match color {
+            PieceColor::White => self.theme.piece_white.as_str(),
+            PieceColor::Black => self.theme.piece_black.as_str(),
+        }
     }
 }
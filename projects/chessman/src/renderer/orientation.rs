@@ -0,0 +1,66 @@
+use super::*;
+
+/// How the logical board is turned on screen
+///
+/// Logical coordinates always keep their meaning, `(0, 0)` is the top left square of the
+/// board as seen from White, only the screen positions move.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Orientation {
+    /// Seen from White
+    #[default]
+    Normal,
+    /// Turned by 180°, seen from Black
+    Flipped,
+    /// Turned by 90° clockwise, White on the left
+    Clockwise,
+    /// Turned by 90° counterclockwise, White on the right
+    CounterClockwise,
+}
+
+impl Orientation {
+    /// Map a logical point to its screen position
+    pub fn map(self, x: f32, y: f32) -> (f32, f32) {
+        let (x, y) = match self {
+            Orientation::Normal => (x, y),
+            Orientation::Flipped => (-x, -y),
+            Orientation::Clockwise => (-y, x),
+            Orientation::CounterClockwise => (y, -x),
+        };
+        // avoid printing `-0` in the svg
+        (x + 0.0, y + 0.0)
+    }
+    /// Whether files run vertically on screen
+    pub fn is_rotated(self) -> bool {
+        matches!(self, Orientation::Clockwise | Orientation::CounterClockwise)
+    }
+    /// The orientation seen from the other side of the board
+    pub fn flipped(self) -> Self {
+        match self {
+            Orientation::Normal => Orientation::Flipped,
+            Orientation::Flipped => Orientation::Normal,
+            Orientation::Clockwise => Orientation::CounterClockwise,
+            Orientation::CounterClockwise => Orientation::Clockwise,
+        }
+    }
+}
+
+impl ChessRender {
+    /// Screen position of a logical point, in grid units
+    pub fn screen_position(&self, x: f32, y: f32) -> (f32, f32) {
+        let (x, y) = self.orientation.map(x, y);
+        (self.round(x * self.grid_size), self.round(y * self.grid_size))
+    }
+    /// Screen position of the center of square `(x, y)`, or of cell `(q, r)` on a hexagonal grid
+    pub fn square_center(&self, x: isize, y: isize) -> (f32, f32) {
+        match self.geometry {
+            Geometry::Square => self.screen_position(x as f32 + 0.5, y as f32 + 0.5),
+            Geometry::Hex => self.hex_position(x as f32, y as f32),
+        }
+    }
+    /// Screen bounds `(left, top, right, bottom)` of a `width` × `height` board
+    pub fn board_bounds(&self, width: f32, height: f32) -> (f32, f32, f32, f32) {
+        let (x1, y1) = self.screen_position(0.0, 0.0);
+        let (x2, y2) = self.screen_position(width, height);
+        (x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2))
+    }
+}
@@ -0,0 +1,66 @@
+use super::*;
+use crate::{Annotations, CastlingRights, ChessPosition, FenError};
+use std::str::FromStr;
+
+impl ChessRender {
+    /// Render a complete board from a FEN string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessman::ChessRender;
+    /// let document = ChessRender::default().render_fen("8/8/8/4k3/8/8/8/4K3 w - - 0 1").unwrap();
+    /// ```
+    pub fn render_fen(&self, fen: &str) -> Result<Document, FenError> {
+        Ok(self.render_position(&ChessPosition::from_str(fen)?))
+    }
+    /// Render a complete board: squares, pieces and the enabled indicators
+    pub fn render_position(&self, position: &ChessPosition) -> Document {
+        self.render_annotated(position, &Annotations::default())
+    }
+    /// Render a complete board with highlights between the board and the pieces, and arrows above them
+    pub fn render_annotated(&self, position: &ChessPosition, annotations: &Annotations) -> Document {
+        self.render_annotated_with(position, annotations, SvgBackend::default())
+    }
+    /// Draw a disc to the right of the board, level with the home rank of the side to move
+    pub fn draw_side_to_move(&self, color: PieceColor) -> Circle {
+        let y = match color {
+            PieceColor::White => 7,
+            PieceColor::Black => 0,
+        };
+        let (_, _, right, _) = self.board_bounds(8.0, 8.0);
+        let (_, cy) = self.square_center(7, y);
+        Circle::new()
+            .set("cx", right + self.margin() + self.grid_size / 4.0)
+            .set("cy", cy)
+            .set("r", self.grid_size / 6.0)
+            .set("fill", self.piece_color(color))
+            .set("stroke", self.theme.piece_black.as_str())
+            .set("stroke-width", self.grid_size / 50.0)
+    }
+    /// Mark the outer corner of every rook square that can still castle
+    pub fn draw_castling(&self, rights: &CastlingRights) -> Group {
+        let size = 0.25;
+        // corners of the board next to the rooks, and the direction pointing inwards
+        let corners = [
+            (rights.white_king_side, PieceColor::White, (8.0, 8.0), (-size, -size)),
+            (rights.white_queen_side, PieceColor::White, (0.0, 8.0), (size, -size)),
+            (rights.black_king_side, PieceColor::Black, (8.0, 0.0), (-size, size)),
+            (rights.black_queen_side, PieceColor::Black, (0.0, 0.0), (size, size)),
+        ];
+        let mut group = Group::new();
+        for &(_, color, (x, y), (dx, dy)) in corners.iter().filter(|corner| corner.0) {
+            let (cx, cy) = self.screen_position(x, y);
+            let (dx, dy) = self.orientation.map(dx * self.grid_size, dy * self.grid_size);
+            let data = Data::new().move_to((cx, cy)).line_by((dx, 0)).line_by((-dx, dy)).close();
+            group = group.add(
+                Path::new()
+                    .set("d", data)
+                    .set("fill", self.piece_color(color))
+                    .set("stroke", self.theme.piece_black.as_str())
+                    .set("stroke-width", self.grid_size / 50.0),
+            );
+        }
+        group
+    }
+}
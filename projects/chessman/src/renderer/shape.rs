@@ -0,0 +1,39 @@
+use super::*;
+use crate::BoardShape;
+
+impl ChessRender {
+    /// Render an empty board of any shape, with its outline and labels
+    pub fn render_shape(&self, shape: &BoardShape) -> Document {
+        self.render_shape_with(shape, SvgBackend::default())
+    }
+    /// Draw the playable squares of a shape, missing squares stay unfilled
+    ///
+    /// Boards with missing squares also get their outline traced, full rectangles look like [`render_position`](Self::render_position).
+    pub fn draw_board(&self, shape: &BoardShape) -> Group {
+        let mut group = Group::new();
+        for (x, y) in shape.squares() {
+            group = group.add(self.draw_square(x, y));
+        }
+        if self.border_width > 0.0 && !shape.is_rectangle() {
+            group = group.add(self.draw_outline(shape));
+        }
+        group
+    }
+    /// Trace the outer border and the holes of a shape
+    pub fn draw_outline(&self, shape: &BoardShape) -> Path {
+        let mut data = Data::new();
+        for corners in shape.outline() {
+            for (i, &(x, y)) in corners.iter().enumerate() {
+                let point = self.screen_position(x as f32, y as f32);
+                data = if i == 0 { data.move_to(point) } else { data.line_to(point) };
+            }
+            data = data.close();
+        }
+        Path::new()
+            .set("d", data)
+            .set("fill", "none")
+            .set("stroke", self.theme.border_color.as_str())
+            .set("stroke-width", self.border_width)
+            .set("stroke-linejoin", "miter")
+    }
+}
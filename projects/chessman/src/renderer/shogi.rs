@@ -0,0 +1,204 @@
+use super::*;
+use crate::{Annotations, FenError, ShogiKind, ShogiPiece, ShogiPosition};
+use std::str::FromStr;
+
+/// Width of a pieces-in-hand panel, in squares
+const HAND_WIDTH: f32 = 1.5;
+
+/// Kanji numerals of the ranks
+const RANK_KANJI: [&str; 9] = ["一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+impl ChessRender {
+    /// Render a shogi board from an SFEN string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessman::{ChessRender, ShogiPosition};
+    /// let document = ChessRender::default().render_sfen(ShogiPosition::STARTING_SFEN).unwrap();
+    /// ```
+    pub fn render_sfen(&self, sfen: &str) -> Result<Document, FenError> {
+        Ok(self.render_shogi(&ShogiPosition::from_str(sfen)?))
+    }
+    /// Render a shogi board with the pieces in hand of gote on the left and of sente on the right
+    pub fn render_shogi(&self, position: &ShogiPosition) -> Document {
+        self.render_shogi_annotated(position, &Annotations::default())
+    }
+    /// Render a shogi board with highlights below the pieces and arrows above them
+    pub fn render_shogi_annotated(&self, position: &ShogiPosition, annotations: &Annotations) -> Document {
+        let margin = self.margin() / self.grid_size;
+        let (x1, y1) = self.screen_position(-margin - HAND_WIDTH, -margin);
+        let (x2, y2) = self.screen_position(9.0 + margin + HAND_WIDTH, 9.0 + margin);
+        let view_box = (x1.min(x2), y1.min(y2), (x2 - x1).abs(), (y2 - y1).abs());
+        let mut document = Document::new().set("viewBox", view_box).add(self.draw_shogi_board());
+        document = document.add(self.draw_shogi_coordinates());
+        document = document.add(self.draw_annotations(annotations));
+        for (x, y, piece) in position.pieces() {
+            document = document.add(self.draw_shogi_piece(piece, x, y));
+        }
+        for &color in [PieceColor::Black, PieceColor::White].iter() {
+            document = document.add(self.draw_shogi_hand(position, color));
+        }
+        if !annotations.arrows.is_empty() {
+            document = document.add(self.draw_arrows(&annotations.arrows));
+        }
+        document
+    }
+    /// Draw the board in one color, with grid lines and the four star points
+    pub fn draw_shogi_board(&self) -> Group {
+        let (left, top, right, bottom) = self.board_bounds(9.0, 9.0);
+        let mut lines = Data::new();
+        for i in 0..10 {
+            lines = lines
+                .move_to(self.screen_position(i as f32, 0.0))
+                .line_to(self.screen_position(i as f32, 9.0))
+                .move_to(self.screen_position(0.0, i as f32))
+                .line_to(self.screen_position(9.0, i as f32));
+        }
+        let mut group = Group::new()
+            .add(
+                Rectangle::new()
+                    .set("x", left)
+                    .set("y", top)
+                    .set("width", right - left)
+                    .set("height", bottom - top)
+                    .set("fill", self.theme.board_white.as_str()),
+            )
+            .add(
+                Path::new()
+                    .set("d", lines)
+                    .set("fill", "none")
+                    .set("stroke", self.theme.border_color.as_str())
+                    .set("stroke-width", self.grid_size / 40.0)
+                    .set("stroke-linecap", "square"),
+            );
+        for &(x, y) in [(3.0, 3.0), (6.0, 3.0), (3.0, 6.0), (6.0, 6.0)].iter() {
+            let (cx, cy) = self.screen_position(x, y);
+            group = group.add(
+                Circle::new()
+                    .set("cx", cx)
+                    .set("cy", cy)
+                    .set("r", self.grid_size / 20.0)
+                    .set("fill", self.theme.border_color.as_str()),
+            );
+        }
+        group
+    }
+    /// Draw file numbers above the board and kanji rank numerals on its right
+    pub fn draw_shogi_coordinates(&self) -> Group {
+        let mut group = Group::new().set("font-family", self.theme.label_font.as_str()).set("text-anchor", "middle");
+        let (font_size, offset) = match self.coordinates {
+            CoordinateStyle::Hidden => return group,
+            CoordinateStyle::Outside => (self.grid_size * 0.3, -self.label_margin / 2.0),
+            CoordinateStyle::Inside => (self.grid_size * 0.22, 0.15),
+        };
+        group = group.set("font-size", font_size).set("fill", self.theme.label_color.as_str());
+        for (i, rank) in RANK_KANJI.iter().enumerate() {
+            let (x, y) = self.screen_position(i as f32 + 0.5, offset);
+            group = group.add(self.draw_label((9 - i).to_string(), x, y + font_size * 0.35));
+            let (x, y) = self.screen_position(9.0 - offset, i as f32 + 0.5);
+            group = group.add(self.draw_label(rank.to_string(), x, y + font_size * 0.35));
+        }
+        group
+    }
+    /// Draw a shogi piece on square `(x, y)`, pointing away from its owner
+    ///
+    /// With the [`PieceSet::Letters`] set the pentagon shows the SFEN letter instead of the kanji, such as
+    /// `P` or `+R`, which needs no CJK font. International symbols are not drawn.
+    pub fn draw_shogi_piece(&self, piece: ShogiPiece, x: isize, y: isize) -> Group {
+        let center = self.square_center(x, y);
+        self.draw_shogi_piece_at(piece, center, 1.0)
+    }
+    /// Draw the pieces one side holds in hand, with their numbers, on its panel beside the board
+    ///
+    /// The sente panel stands right of the board and fills from the bottom, the gote panel stands
+    /// left of it and fills from the top.
+    pub fn draw_shogi_hand(&self, position: &ShogiPosition, color: PieceColor) -> Group {
+        let margin = self.margin() / self.grid_size;
+        let (x, first, step) = match color {
+            PieceColor::Black => (9.0 + margin + HAND_WIDTH / 2.0, 8.5, -1.0),
+            PieceColor::White => (-margin - HAND_WIDTH / 2.0, 0.5, 1.0),
+        };
+        let (left, top) = self.screen_position(x - 0.5, 0.0);
+        let (right, bottom) = self.screen_position(x + 0.5, 9.0);
+        let mut group = Group::new().add(
+            Rectangle::new()
+                .set("x", left.min(right))
+                .set("y", top.min(bottom))
+                .set("width", (right - left).abs())
+                .set("height", (bottom - top).abs())
+                .set("fill", self.theme.board_white.as_str()),
+        );
+        let font_size = self.grid_size * 0.25;
+        for (i, (kind, count)) in position.hand(color).enumerate() {
+            let y = first + step * i as f32;
+            let center = self.screen_position(x, y);
+            group = group.add(self.draw_shogi_piece_at(ShogiPiece::new(kind, color), center, 0.8));
+            if count > 1 {
+                let (cx, cy) = self.screen_position(x + 0.35, y + 0.35);
+                group = group.add(
+                    Text::new()
+                        .set("x", cx)
+                        .set("y", cy + font_size * 0.35)
+                        .set("text-anchor", "middle")
+                        .set("font-family", self.theme.label_font.as_str())
+                        .set("font-size", font_size)
+                        .set("font-weight", "bold")
+                        .set("fill", self.theme.label_color.as_str())
+                        .add(svg::node::Text::new(count.to_string())),
+                );
+            }
+        }
+        group
+    }
+    fn draw_shogi_piece_at(&self, piece: ShogiPiece, (cx, cy): (f32, f32), scale: f32) -> Group {
+        // higher ranking pieces are cut larger
+        let size = match piece.kind {
+            ShogiKind::King => 1.0,
+            ShogiKind::Rook | ShogiKind::Bishop => 0.97,
+            ShogiKind::Gold | ShogiKind::Silver => 0.94,
+            ShogiKind::Knight => 0.91,
+            ShogiKind::Lance => 0.88,
+            ShogiKind::Pawn => 0.85,
+        } * scale
+            * self.grid_size;
+        let outline = [(0.0, -0.44), (0.3, -0.31), (0.37, 0.43), (-0.37, 0.43), (-0.3, -0.31)];
+        let mut data = Data::new().move_to((cx, cy - 0.44 * size));
+        for &(dx, dy) in &outline[1..] {
+            data = data.line_to((cx + dx * size, cy + dy * size));
+        }
+        let quarter_turns = match self.orientation {
+            Orientation::Normal => 0,
+            Orientation::Clockwise => 1,
+            Orientation::Flipped => 2,
+            Orientation::CounterClockwise => 3,
+        } + if piece.color == PieceColor::White { 2 } else { 0 };
+        let label = match self.theme.piece_set {
+            PieceSet::Classic => piece.kanji().to_string(),
+            PieceSet::Letters => piece.sfen().to_ascii_uppercase(),
+        };
+        let font_size = size * if label.chars().count() > 1 { 0.32 } else { 0.42 };
+        let ink = if piece.promoted { self.theme.piece_red.as_str() } else { self.theme.piece_black.as_str() };
+        Group::new()
+            .set("transform", format!("rotate({} {} {})", quarter_turns % 4 * 90, cx, cy))
+            .add(
+                Path::new()
+                    .set("d", data.close())
+                    .set("fill", self.theme.piece_white.as_str())
+                    .set("stroke", self.theme.piece_black.as_str())
+                    .set("stroke-width", self.grid_size / 50.0)
+                    .set("stroke-linejoin", "round"),
+            )
+            .add(
+                Text::new()
+                    .set("x", cx)
+                    .set("y", cy + 0.05 * size + font_size * 0.35)
+                    .set("text-anchor", "middle")
+                    .set("font-family", self.theme.label_font.as_str())
+                    .set("font-size", font_size)
+                    .set("font-weight", "bold")
+                    .set("fill", ink)
+                    .add(svg::node::Text::new(label)),
+            )
+    }
+}
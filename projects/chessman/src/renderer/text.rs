@@ -0,0 +1,241 @@
+use super::*;
+use crate::{ChessPiece, RenderBackend};
+
+/// Draws boards with Unicode figurines for terminals, colored by the theme with ANSI escapes
+///
+/// Paths can not be drawn in text, tours show their numbered steps instead.
+#[derive(Clone, Debug)]
+pub struct TerminalBackend {
+    /// Paint squares and pieces with 24-bit ANSI colors, plain text otherwise
+    pub ansi: bool,
+    grid: TextGrid,
+}
+
+/// Draws boards in plain ASCII, pieces by their FEN letters and empty squares as `.`
+#[derive(Clone, Debug, Default)]
+pub struct AsciiBackend {
+    grid: TextGrid,
+}
+
+/// Square contents by logical coordinates, shared by the text backends
+#[derive(Clone, Debug, Default)]
+struct TextGrid {
+    render: ChessRender,
+    width: isize,
+    height: isize,
+    cells: Vec<Option<TextCell>>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct TextCell {
+    piece: Option<ChessPiece>,
+    point: bool,
+    step: Option<usize>,
+}
+
+impl Default for TerminalBackend {
+    fn default() -> Self {
+        Self { ansi: true, grid: TextGrid::default() }
+    }
+}
+
+impl TerminalBackend {
+    /// A terminal backend without colors
+    pub fn plain() -> Self {
+        Self { ansi: false, ..Self::default() }
+    }
+    fn glyph(&self, cell: &TextCell) -> String {
+        match (cell.piece, cell.step) {
+            (Some(piece), _) => unicode_piece(piece, self.ansi).to_string(),
+            (None, Some(step)) => step.to_string(),
+            (None, None) if cell.point => "•".to_string(),
+            _ if self.ansi => " ".to_string(),
+            _ => "·".to_string(),
+        }
+    }
+}
+
+impl TextGrid {
+    fn begin(&mut self, render: &ChessRender, width: isize, height: isize) {
+        *self = Self { render: render.clone(), width, height, cells: vec![None; (width * height).max(0) as usize] };
+    }
+    fn cell(&mut self, x: isize, y: isize) -> Option<&mut TextCell> {
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return None;
+        }
+        self.cells[(y * self.width + x) as usize].as_mut()
+    }
+    /// Size of the board on screen, in cells
+    fn screen_size(&self) -> (isize, isize) {
+        if self.render.orientation.is_rotated() {
+            (self.height, self.width)
+        }
+        else {
+            (self.width, self.height)
+        }
+    }
+    /// The logical square shown in screen column `sx` and row `sy`
+    fn logical(&self, sx: isize, sy: isize) -> (isize, isize) {
+        let (w, h) = (self.width, self.height);
+        match self.render.orientation {
+            Orientation::Normal => (sx, sy),
+            Orientation::Flipped => (w - 1 - sx, h - 1 - sy),
+            Orientation::Clockwise => (sy, h - 1 - sx),
+            Orientation::CounterClockwise => (w - 1 - sy, sx),
+        }
+    }
+    /// Lay the cells out row by row, `draw` gets the padded text and the square of every cell
+    fn lines(&self, cell_text: impl Fn(&TextCell) -> String, draw: impl Fn(String, (isize, isize), bool) -> String) -> String {
+        let (columns, rows) = self.screen_size();
+        let texts: Vec<Option<String>> = self.cells.iter().map(|cell| cell.as_ref().map(&cell_text)).collect();
+        let cell_width = texts.iter().flatten().map(|text| text.chars().count()).max().unwrap_or(1);
+        let labels = self.render.coordinates != CoordinateStyle::Hidden;
+        let rotated = self.render.orientation.is_rotated();
+        let row_labels: Vec<String> = (0..rows)
+            .map(|sy| {
+                let (x, y) = self.logical(0, sy);
+                if rotated {
+                    file_label(x as usize)
+                }
+                else {
+                    rank_label(y as usize, self.height as usize)
+                }
+            })
+            .collect();
+        let label_width = row_labels.iter().map(|label| label.len()).max().unwrap_or(0);
+        let mut out = String::new();
+        for (sy, row_label) in row_labels.iter().enumerate() {
+            let mut line = String::new();
+            if labels {
+                line.push_str(&format!("{:>width$} ", row_label, width = label_width));
+            }
+            for sx in 0..columns {
+                let (x, y) = self.logical(sx, sy as isize);
+                let text = texts[(y * self.width + x) as usize].clone();
+                let filled = text.is_some();
+                line.push_str(&draw(format!("{:>width$}", text.unwrap_or_default(), width = cell_width), (x, y), filled));
+            }
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        if labels {
+            let mut line = " ".repeat(label_width + 1);
+            for sx in 0..columns {
+                let (x, y) = self.logical(sx, rows - 1);
+                let label = if rotated { rank_label(y as usize, self.height as usize) } else { file_label(x as usize) };
+                line.push_str(&draw(format!("{:>width$}", label, width = cell_width), (x, y), false));
+            }
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        out
+    }
+}
+
+macro_rules! text_backend {
+    ($backend:ty) => {
+        impl RenderBackend for $backend {
+            type Output = String;
+
+            fn begin(&mut self, render: &ChessRender, width: isize, height: isize) {
+                self.grid.begin(render, width, height);
+            }
+            fn square(&mut self, x: isize, y: isize) {
+                if x >= 0 && y >= 0 && x < self.grid.width && y < self.grid.height {
+                    self.grid.cells[(y * self.grid.width + x) as usize] = Some(TextCell::default());
+                }
+            }
+            fn piece(&mut self, piece: ChessPiece, x: isize, y: isize) {
+                if let Some(cell) = self.grid.cell(x, y) {
+                    cell.piece = Some(piece);
+                }
+            }
+            fn path(&mut self, _: (isize, isize), _: (isize, isize)) {}
+            fn point(&mut self, x: isize, y: isize) {
+                if let Some(cell) = self.grid.cell(x, y) {
+                    cell.point = true;
+                }
+            }
+            fn step(&mut self, x: isize, y: isize, step: usize) {
+                if let Some(cell) = self.grid.cell(x, y) {
+                    cell.step = Some(step);
+                }
+            }
+            fn finish(self) -> String {
+                self.draw()
+            }
+        }
+    };
+}
+
+text_backend!(TerminalBackend);
+text_backend!(AsciiBackend);
+
+impl TerminalBackend {
+    fn draw(&self) -> String {
+        let grid = &self.grid;
+        let theme = &grid.render.theme;
+        grid.lines(
+            |cell| self.glyph(cell),
+            |text, (x, y), filled| {
+                if !self.ansi {
+                    return format!("{} ", text);
+                }
+                if !filled {
+                    return format!(" {} ", text);
+                }
+                let cell = grid.cells[(y * grid.width + x) as usize].as_ref();
+                let foreground = match cell.and_then(|cell| cell.piece) {
+                    Some(piece) => grid.render.piece_color(piece.color),
+                    None if cell.is_some_and(|cell| cell.step.is_none()) => theme.path_color.as_str(),
+                    None => "#000000",
+                };
+                format!("{}{} {} \x1b[0m", ansi_color(grid.render.grid_color(x, y), 48), ansi_color(foreground, 38), text)
+            },
+        )
+    }
+}
+
+impl AsciiBackend {
+    fn draw(&self) -> String {
+        let glyph = |cell: &TextCell| match (cell.piece, cell.step) {
+            (Some(piece), _) => piece.fen_char().to_string(),
+            (None, Some(step)) => step.to_string(),
+            (None, None) if cell.point => "*".to_string(),
+            _ => ".".to_string(),
+        };
+        self.grid.lines(glyph, |text, _, _| format!("{} ", text))
+    }
+}
+
+/// Figurine of a piece, solid ones for both sides when colors tell them apart
+fn unicode_piece(piece: ChessPiece, solid: bool) -> char {
+    let figures = if solid || piece.color == PieceColor::Black { "♚♛♜♝♞♟" } else { "♔♕♖♗♘♙" };
+    let index = match piece.kind {
+        PieceKind::King => 0,
+        PieceKind::Queen => 1,
+        PieceKind::Rook => 2,
+        PieceKind::Bishop => 3,
+        PieceKind::Knight => 4,
+        PieceKind::Pawn => 5,
+    };
+    figures.chars().nth(index).unwrap_or('?')
+}
+
+/// ANSI escape setting a `#rgb` or `#rrggbb` color, `layer` is 38 for the text and 48 for the background
+///
+/// Other CSS colors have no escape and keep the terminal default.
+fn ansi_color(color: &str, layer: u8) -> String {
+    let hex = color.trim_start_matches('#');
+    let digits: Vec<u8> = match hex.len() {
+        3 if color.starts_with('#') => hex.chars().filter_map(|c| c.to_digit(16)).map(|d| d as u8 * 17).collect(),
+        6 if color.starts_with('#') => {
+            (0..3).filter_map(|i| hex.get(2 * i..2 * i + 2)).filter_map(|pair| u8::from_str_radix(pair, 16).ok()).collect()
+        }
+        _ => vec![],
+    };
+    match digits.as_slice() {
+        [r, g, b] => format!("\x1b[{};2;{};{};{}m", layer, r, g, b),
+        _ => String::new(),
+    }
+}
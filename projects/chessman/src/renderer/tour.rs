@@ -0,0 +1,18 @@
+use super::*;
+use crate::KnightTour;
+
+impl ChessRender {
+    /// Render a knight's tour with connecting lines and numbered steps
+    ///
+    /// Shaped boards only show their playable squares and get an outline, rectangular ones are drawn as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessman::{knights_tour, ChessRender};
+    /// let document = ChessRender::default().render_tour(&knights_tour(5, 5).unwrap());
+    /// ```
+    pub fn render_tour(&self, tour: &KnightTour) -> Document {
+        self.render_tour_with(tour, SvgBackend::default())
+    }
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::{Annotations, FenError, XiangqiPiece, XiangqiPosition};
+use std::str::FromStr;
+
+impl ChessRender {
+    /// Render a xiangqi board from a WXF FEN string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chessman::{ChessRender, XiangqiPosition};
+    /// let document =
+    ///     ChessRender::default().render_xiangqi_fen(XiangqiPosition::STARTING_FEN).unwrap();
+    /// ```
+    pub fn render_xiangqi_fen(&self, fen: &str) -> Result<Document, FenError> {
+        Ok(self.render_xiangqi(&XiangqiPosition::from_str(fen)?))
+    }
+    /// Render a xiangqi board with its pieces on the intersections
+    pub fn render_xiangqi(&self, position: &XiangqiPosition) -> Document {
+        self.render_xiangqi_annotated(position, &Annotations::default())
+    }
+    /// Render a xiangqi board with highlights below the pieces and arrows above them
+    pub fn render_xiangqi_annotated(&self, position: &XiangqiPosition, annotations: &Annotations) -> Document {
+        let mut document = self.document(9.0, 10.0).add(self.draw_xiangqi_board());
+        document = document.add(self.draw_annotations(annotations));
+        for (x, y, piece) in position.pieces() {
+            document = document.add(self.draw_xiangqi_piece(piece, x, y));
+        }
+        if !annotations.arrows.is_empty() {
+            document = document.add(self.draw_arrows(&annotations.arrows));
+        }
+        document
+    }
+    /// Draw the lines of a xiangqi board: the grid broken by the river, both palaces and the marks
+    /// on the starting points of soldiers and cannons
+    pub fn draw_xiangqi_board(&self) -> Group {
+        let (left, top, right, bottom) = self.board_bounds(9.0, 10.0);
+        let line = |x1: f32, y1: f32, x2: f32, y2: f32| {
+            let data =
+                Data::new().move_to(self.screen_position(x1 + 0.5, y1 + 0.5)).line_to(self.screen_position(x2 + 0.5, y2 + 0.5));
+            Path::new().set("d", data)
+        };
+        let mut lines = Group::new()
+            .set("fill", "none")
+            .set("stroke", self.theme.border_color.as_str())
+            .set("stroke-width", self.grid_size / 40.0)
+            .set("stroke-linecap", "square");
+        for y in 0..10 {
+            lines = lines.add(line(0.0, y as f32, 8.0, y as f32));
+        }
+        for x in 0..9 {
+            if x == 0 || x == 8 {
+                lines = lines.add(line(x as f32, 0.0, x as f32, 9.0));
+            }
+            else {
+                lines = lines.add(line(x as f32, 0.0, x as f32, 4.0)).add(line(x as f32, 5.0, x as f32, 9.0));
+            }
+        }
+        for &y in [0.0, 7.0].iter() {
+            lines = lines.add(line(3.0, y, 5.0, y + 2.0)).add(line(5.0, y, 3.0, y + 2.0));
+        }
+        let (gap, length) = (0.08, 0.18);
+        let mut marks = Data::new();
+        let points = [1, 7].iter().map(|&x| (x, 2)).chain((0..9).step_by(2).map(|x| (x, 3)));
+        for (x, y) in points.flat_map(|(x, y)| vec![(x, y), (x, 9 - y)]) {
+            for &(sx, sy) in [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)].iter() {
+                if (x == 0 && sx < 0.0) || (x == 8 && sx > 0.0) {
+                    continue;
+                }
+                let (cx, cy) = (x as f32 + 0.5, y as f32 + 0.5);
+                marks = marks
+                    .move_to(self.screen_position(cx + sx * gap, cy + sy * (gap + length)))
+                    .line_to(self.screen_position(cx + sx * gap, cy + sy * gap))
+                    .line_to(self.screen_position(cx + sx * (gap + length), cy + sy * gap));
+            }
+        }
+        lines = lines.add(Path::new().set("d", marks));
+        let frame = Rectangle::new()
+            .set("x", left + self.grid_size * 0.3)
+            .set("y", top + self.grid_size * 0.3)
+            .set("width", right - left - self.grid_size * 0.6)
+            .set("height", bottom - top - self.grid_size * 0.6)
+            .set("stroke-width", self.grid_size / 20.0);
+        let font_size = self.grid_size * 0.5;
+        let mut river = Group::new()
+            .set("font-family", self.theme.label_font.as_str())
+            .set("font-size", font_size)
+            .set("text-anchor", "middle")
+            .set("fill", self.theme.border_color.as_str());
+        for &(x, text) in [(2.5, "楚　河"), (6.5, "漢　界")].iter() {
+            let (cx, cy) = self.screen_position(x, 5.0);
+            river = river.add(Text::new().set("x", cx).set("y", cy + font_size * 0.35).add(svg::node::Text::new(text)));
+        }
+        let background = Rectangle::new()
+            .set("x", left)
+            .set("y", top)
+            .set("width", right - left)
+            .set("height", bottom - top)
+            .set("fill", self.theme.board_white.as_str());
+        Group::new().add(background).add(lines.add(frame)).add(river)
+    }
+    /// Draw a xiangqi piece as a disc with its character on point `(x, y)`
+    ///
+    /// With the [`PieceSet::Letters`] set the disc shows the WXF letter instead, which needs no CJK font.
+    pub fn draw_xiangqi_piece(&self, piece: XiangqiPiece, x: isize, y: isize) -> Group {
+        let (cx, cy) = self.square_center(x, y);
+        let color = match piece.color {
+            PieceColor::White => self.theme.piece_red.as_str(),
+            PieceColor::Black => self.theme.piece_black.as_str(),
+        };
+        let radius = self.grid_size * 0.44;
+        let label = match self.theme.piece_set {
+            PieceSet::Classic => piece.character().to_string(),
+            PieceSet::Letters => piece.kind.fen_char().to_ascii_uppercase().to_string(),
+        };
+        let font_size = self.grid_size * 0.5;
+        Group::new()
+            .set("stroke", color)
+            .add(
+                Circle::new()
+                    .set("cx", cx)
+                    .set("cy", cy)
+                    .set("r", radius)
+                    .set("fill", self.theme.piece_white.as_str())
+                    .set("stroke-width", self.grid_size / 25.0),
+            )
+            .add(
+                Circle::new()
+                    .set("cx", cx)
+                    .set("cy", cy)
+                    .set("r", radius * 0.82)
+                    .set("fill", "none")
+                    .set("stroke-width", self.grid_size / 50.0),
+            )
+            .add(
+                Text::new()
+                    .set("x", cx)
+                    .set("y", cy + font_size * 0.35)
+                    .set("text-anchor", "middle")
+                    .set("font-family", self.theme.label_font.as_str())
+                    .set("font-size", font_size)
+                    .set("font-weight", "bold")
+                    .set("stroke", "none")
+                    .set("fill", color)
+                    .add(svg::node::Text::new(label)),
+            )
+    }
+}
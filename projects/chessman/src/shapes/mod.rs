@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+/// The playable squares of a board, which need not fill its bounding rectangle
+///
+/// # Examples
+///
+/// ```
+/// use chessman::BoardShape;
+/// let cross = BoardShape::from_rows(&[".##.", "####", "####", ".##."]);
+/// assert_eq!(cross.count(), 12);
+/// assert!(!cross.contains(0, 0));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BoardShape {
+    width: usize,
+    height: usize,
+    squares: Vec<bool>,
+}
+
+impl BoardShape {
+    /// Every square of a `width` × `height` board
+    pub fn rectangle(width: usize, height: usize) -> Self {
+        Self { width, height, squares: vec![true; width * height] }
+    }
+    /// A `width` × `height` board without any square
+    pub fn empty(width: usize, height: usize) -> Self {
+        Self { width, height, squares: vec![false; width * height] }
+    }
+    /// Read a shape row by row from the top, `.` and spaces are missing squares
+    pub fn from_rows(rows: &[&str]) -> Self {
+        let width = rows.iter().map(|row| row.chars().count()).max().unwrap_or(0);
+        let mut shape = Self::empty(width, rows.len());
+        for (y, row) in rows.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                if c != '.' && c != ' ' {
+                    shape.insert(x as isize, y as isize);
+                }
+            }
+        }
+        shape
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// Number of playable squares
+    pub fn count(&self) -> usize {
+        self.squares.iter().filter(|&&square| square).count()
+    }
+    /// Whether every square of the bounding rectangle is playable
+    pub fn is_rectangle(&self) -> bool {
+        self.squares.iter().all(|&square| square)
+    }
+    /// Whether `(x, y)` is a playable square, squares off the board never are
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        self.index(x, y).is_some_and(|index| self.squares[index])
+    }
+    /// Make `(x, y)` playable
+    ///
+    /// # Panics
+    ///
+    /// If the square is outside the bounding rectangle.
+    pub fn insert(&mut self, x: isize, y: isize) -> &mut Self {
+        let index = self.index(x, y).expect("square outside of the board");
+        self.squares[index] = true;
+        self
+    }
+    /// Cut `(x, y)` out of the board
+    pub fn remove(&mut self, x: isize, y: isize) -> &mut Self {
+        if let Some(index) = self.index(x, y) {
+            self.squares[index] = false;
+        }
+        self
+    }
+    /// Playable squares, row by row from the top
+    pub fn squares(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        let width = self.width;
+        self.squares
+            .iter()
+            .enumerate()
+            .filter(|(_, &square)| square)
+            .map(move |(i, _)| ((i % width) as isize, (i / width) as isize))
+    }
+    /// Closed outlines around the playable squares, as corners of the grid
+    ///
+    /// Outer borders run clockwise on screen, holes run counterclockwise.
+    pub fn outline(&self) -> Vec<Vec<(isize, isize)>> {
+        // every square edge facing a missing square, with the square on its right
+        let mut edges: BTreeMap<(isize, isize), Vec<(isize, isize)>> = BTreeMap::new();
+        for (x, y) in self.squares() {
+            let sides = [
+                ((x, y - 1), (x, y), (x + 1, y)),
+                ((x + 1, y), (x + 1, y), (x + 1, y + 1)),
+                ((x, y + 1), (x + 1, y + 1), (x, y + 1)),
+                ((x - 1, y), (x, y + 1), (x, y)),
+            ];
+            for &((nx, ny), from, to) in sides.iter() {
+                if !self.contains(nx, ny) {
+                    edges.entry(from).or_default().push(to);
+                }
+            }
+        }
+        let mut loops = vec![];
+        while let Some((&start, _)) = edges.iter().next() {
+            let mut corners = vec![start];
+            let mut current = start;
+            while let Some(next) = edges.get_mut(&current).and_then(|targets| targets.pop()) {
+                if edges[&current].is_empty() {
+                    edges.remove(&current);
+                }
+                current = next;
+                if current == start {
+                    break;
+                }
+                corners.push(current);
+            }
+            loops.push(simplify(corners));
+        }
+        loops
+    }
+    fn index(&self, x: isize, y: isize) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        Some(y as usize * self.width + x as usize)
+    }
+}
+
+/// Drop corners in the middle of straight runs
+fn simplify(corners: Vec<(isize, isize)>) -> Vec<(isize, isize)> {
+    let length = corners.len();
+    (0..length)
+        .filter(|&i| {
+            let (px, py) = corners[(i + length - 1) % length];
+            let (x, y) = corners[i];
+            let (nx, ny) = corners[(i + 1) % length];
+            (x - px) * (ny - y) != (y - py) * (nx - x)
+        })
+        .map(|i| corners[i])
+        .collect()
+}
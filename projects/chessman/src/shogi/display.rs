@@ -0,0 +1,46 @@
+use super::*;
+
+impl Display for ShogiPosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in 0..9 {
+            if y != 0 {
+                write!(f, "/")?;
+            }
+            let mut empty = 0;
+            for x in 0..9 {
+                match self.piece_at(x, y) {
+                    Some(piece) => {
+                        if empty != 0 {
+                            write!(f, "{}", empty)?;
+                            empty = 0;
+                        }
+                        write!(f, "{}", piece.sfen())?;
+                    }
+                    None => empty += 1,
+                }
+            }
+            if empty != 0 {
+                write!(f, "{}", empty)?;
+            }
+        }
+        let side = match self.side_to_move {
+            PieceColor::Black => 'b',
+            PieceColor::White => 'w',
+        };
+        write!(f, " {} ", side)?;
+        let mut empty = true;
+        for &color in [PieceColor::Black, PieceColor::White].iter() {
+            for (kind, count) in self.hand(color) {
+                if count > 1 {
+                    write!(f, "{}", count)?;
+                }
+                write!(f, "{}", ShogiPiece::new(kind, color).sfen())?;
+                empty = false;
+            }
+        }
+        if empty {
+            write!(f, "-")?;
+        }
+        write!(f, " {}", self.move_number)
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{FenError, PieceColor};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+mod display;
+
+/// Kinds of shogi pieces, in the order pieces in hand are listed
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ShogiKind {
+    King,
+    Rook,
+    Bishop,
+    Gold,
+    Silver,
+    Knight,
+    Lance,
+    Pawn,
+}
+
+/// A shogi piece
+///
+/// Sente moves first and plays the [`PieceColor::Black`] side, its pieces are uppercase in SFEN.
+/// Gote plays [`PieceColor::White`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ShogiPiece {
+    pub kind: ShogiKind,
+    pub color: PieceColor,
+    /// Turned over after entering the promotion zone
+    pub promoted: bool,
+}
+
+/// A shogi position decoded from SFEN, with the pieces in hand of both sides
+///
+/// `x` runs from file 9 on the left to file 1 on the right, `y` from rank a (一) at the top,
+/// on the gote side, to rank i (九) at the bottom.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShogiPosition {
+    squares: [Option<ShogiPiece>; 81],
+    /// Captured pieces of sente and gote
+    hands: [BTreeMap<ShogiKind, u32>; 2],
+    /// The side to move, sente is [`PieceColor::Black`]
+    pub side_to_move: PieceColor,
+    /// Number of the move, starting at 1
+    pub move_number: u32,
+}
+
+impl ShogiKind {
+    /// All kinds, king first
+    pub const ALL: [ShogiKind; 8] = [
+        ShogiKind::King,
+        ShogiKind::Rook,
+        ShogiKind::Bishop,
+        ShogiKind::Gold,
+        ShogiKind::Silver,
+        ShogiKind::Knight,
+        ShogiKind::Lance,
+        ShogiKind::Pawn,
+    ];
+
+    /// Read an SFEN letter, ignoring case
+    pub fn from_sfen_char(c: char) -> Option<Self> {
+        match c.to_ascii_lowercase() {
+            'k' => Some(ShogiKind::King),
+            'r' => Some(ShogiKind::Rook),
+            'b' => Some(ShogiKind::Bishop),
+            'g' => Some(ShogiKind::Gold),
+            's' => Some(ShogiKind::Silver),
+            'n' => Some(ShogiKind::Knight),
+            'l' => Some(ShogiKind::Lance),
+            'p' => Some(ShogiKind::Pawn),
+            _ => None,
+        }
+    }
+    /// The lowercase SFEN letter
+    pub fn sfen_char(self) -> char {
+        match self {
+            ShogiKind::King => 'k',
+            ShogiKind::Rook => 'r',
+            ShogiKind::Bishop => 'b',
+            ShogiKind::Gold => 'g',
+            ShogiKind::Silver => 's',
+            ShogiKind::Knight => 'n',
+            ShogiKind::Lance => 'l',
+            ShogiKind::Pawn => 'p',
+        }
+    }
+    /// Whether the piece can be promoted, kings and golds can not
+    pub fn can_promote(self) -> bool {
+        !matches!(self, ShogiKind::King | ShogiKind::Gold)
+    }
+    /// The kanji written on the piece, or on its back once promoted
+    ///
+    /// The sente king is written 玉 and the gote king 王.
+    pub fn kanji(self, color: PieceColor, promoted: bool) -> char {
+        match (self, promoted) {
+            (ShogiKind::King, _) if color == PieceColor::Black => '玉',
+            (ShogiKind::King, _) => '王',
+            (ShogiKind::Rook, false) => '飛',
+            (ShogiKind::Rook, true) => '龍',
+            (ShogiKind::Bishop, false) => '角',
+            (ShogiKind::Bishop, true) => '馬',
+            (ShogiKind::Gold, _) => '金',
+            (ShogiKind::Silver, false) => '銀',
+            (ShogiKind::Silver, true) => '全',
+            (ShogiKind::Knight, false) => '桂',
+            (ShogiKind::Knight, true) => '圭',
+            (ShogiKind::Lance, false) => '香',
+            (ShogiKind::Lance, true) => '杏',
+            (ShogiKind::Pawn, false) => '歩',
+            (ShogiKind::Pawn, true) => 'と',
+        }
+    }
+}
+
+impl ShogiPiece {
+    pub fn new(kind: ShogiKind, color: PieceColor) -> Self {
+        Self { kind, color, promoted: false }
+    }
+    /// The same piece turned over
+    pub fn promote(self) -> Self {
+        Self { promoted: self.kind.can_promote(), ..self }
+    }
+    /// Read an SFEN letter, uppercase for sente and lowercase for gote
+    pub fn from_sfen_char(c: char) -> Option<Self> {
+        let color = if c.is_ascii_uppercase() { PieceColor::Black } else { PieceColor::White };
+        ShogiKind::from_sfen_char(c).map(|kind| Self::new(kind, color))
+    }
+    /// The SFEN notation, such as `P`, `+r` or `k`
+    pub fn sfen(self) -> String {
+        let letter = match self.color {
+            PieceColor::Black => self.kind.sfen_char().to_ascii_uppercase(),
+            PieceColor::White => self.kind.sfen_char(),
+        };
+        if self.promoted {
+            format!("+{}", letter)
+        }
+        else {
+            letter.to_string()
+        }
+    }
+    /// The kanji written on the piece
+    pub fn kanji(self) -> char {
+        self.kind.kanji(self.color, self.promoted)
+    }
+}
+
+impl Default for ShogiPosition {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl ShogiPosition {
+    /// SFEN of the starting position
+    pub const STARTING_SFEN: &'static str = "lnsgkgsnl/1r5b1/ppppppppp/9/9/9/PPPPPPPPP/1B5R1/LNSGKGSNL b - 1";
+
+    /// A board without any pieces, sente to move
+    pub fn empty() -> Self {
+        Self { squares: [None; 81], hands: Default::default(), side_to_move: PieceColor::Black, move_number: 1 }
+    }
+    /// The starting position
+    pub fn starting() -> Self {
+        Self::from_str(Self::STARTING_SFEN).expect("starting position is valid")
+    }
+    /// The piece on square `(x, y)`, if any
+    pub fn piece_at(&self, x: isize, y: isize) -> Option<ShogiPiece> {
+        if (0..9).contains(&x) && (0..9).contains(&y) {
+            self.squares[(y * 9 + x) as usize]
+        }
+        else {
+            None
+        }
+    }
+    /// Put a piece on square `(x, y)`, or clear it with `None`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the square is outside the board.
+    pub fn set_piece(&mut self, x: isize, y: isize, piece: Option<ShogiPiece>) {
+        assert!((0..9).contains(&x) && (0..9).contains(&y), "square ({}, {}) is outside the board", x, y);
+        self.squares[(y * 9 + x) as usize] = piece;
+    }
+    /// All pieces on the board with their squares, gote side first
+    pub fn pieces(&self) -> impl Iterator<Item = (isize, isize, ShogiPiece)> + '_ {
+        self.squares.iter().enumerate().filter_map(|(i, piece)| piece.map(|p| (i as isize % 9, i as isize / 9, p)))
+    }
+    /// Pieces in the hand of a side with their numbers, rook first
+    pub fn hand(&self, color: PieceColor) -> impl Iterator<Item = (ShogiKind, u32)> + '_ {
+        self.hands[hand_index(color)].iter().map(|(&kind, &count)| (kind, count)).filter(|&(_, count)| count > 0)
+    }
+    /// Set how many pieces of a kind a side holds in hand
+    pub fn set_hand(&mut self, color: PieceColor, kind: ShogiKind, count: u32) {
+        let hand = &mut self.hands[hand_index(color)];
+        if count == 0 {
+            hand.remove(&kind);
+        }
+        else {
+            hand.insert(kind, count);
+        }
+    }
+}
+
+fn hand_index(color: PieceColor) -> usize {
+    match color {
+        PieceColor::Black => 0,
+        PieceColor::White => 1,
+    }
+}
+
+impl FromStr for ShogiPosition {
+    type Err = FenError;
+
+    fn from_str(sfen: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = sfen.split_whitespace().collect();
+        if fields.len() > 4 {
+            return Err(FenError::TooManyFields(fields.len()));
+        }
+        let mut position = ShogiPosition::empty();
+        let board = fields.first().ok_or(FenError::MissingBoard)?;
+        let ranks: Vec<&str> = board.split('/').collect();
+        if ranks.len() != 9 {
+            return Err(FenError::RankCount { ranks: ranks.len(), expected: 9 });
+        }
+        for (y, rank) in ranks.iter().enumerate() {
+            let mut x = 0;
+            let mut promoted = false;
+            for c in rank.chars() {
+                if c == '+' && !promoted {
+                    promoted = true;
+                    continue;
+                }
+                match c.to_digit(10) {
+                    Some(skip @ 1..=9) if !promoted => x += skip as usize,
+                    Some(_) => return Err(FenError::InvalidPiece(c)),
+                    None => {
+                        let piece = ShogiPiece::from_sfen_char(c).ok_or(FenError::InvalidPiece(c))?;
+                        if promoted && !piece.kind.can_promote() {
+                            return Err(FenError::InvalidPiece(c));
+                        }
+                        if x < 9 {
+                            position.squares[y * 9 + x] = Some(if promoted { piece.promote() } else { piece });
+                        }
+                        x += 1;
+                    }
+                }
+                promoted = false;
+            }
+            if promoted {
+                return Err(FenError::InvalidPiece('+'));
+            }
+            if x != 9 {
+                return Err(FenError::RankLength { rank: 9 - y, files: x, expected: 9 });
+            }
+        }
+        if let Some(side) = fields.get(1) {
+            position.side_to_move = match *side {
+                "b" => PieceColor::Black,
+                "w" => PieceColor::White,
+                _ => return Err(FenError::InvalidSideToMove(side.to_string())),
+            };
+        }
+        if let Some(hands) = fields.get(2).filter(|hands| **hands != "-") {
+            let error = || FenError::InvalidHand(hands.to_string());
+            let mut count: Option<u32> = None;
+            for c in hands.chars() {
+                match c.to_digit(10) {
+                    Some(digit) => {
+                        count = Some(count.unwrap_or(0).checked_mul(10).and_then(|c| c.checked_add(digit)).ok_or_else(error)?)
+                    }
+                    None => {
+                        let piece = ShogiPiece::from_sfen_char(c).filter(|p| p.kind != ShogiKind::King).ok_or_else(error)?;
+                        let added = count.take().unwrap_or(1);
+                        if added == 0 {
+                            return Err(error());
+                        }
+                        let held = position.hands[hand_index(piece.color)].entry(piece.kind).or_insert(0);
+                        *held = held.checked_add(added).ok_or_else(error)?;
+                    }
+                }
+            }
+            if count.is_some() {
+                return Err(error());
+            }
+        }
+        if let Some(counter) = fields.get(3) {
+            position.move_number = counter.parse().map_err(|_| FenError::InvalidCounter(counter.to_string()))?;
+        }
+        Ok(position)
+    }
+}
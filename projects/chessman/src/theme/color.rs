@@ -0,0 +1,201 @@
+/// CSS named colors, plus `transparent` and `currentcolor`
+const NAMED_COLORS: &[&str] = &[
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+    "transparent",
+    "currentcolor",
+];
+
+/// Whether `color` is a CSS color: a named color, `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`,
+/// or an `rgb()`, `rgba()`, `hsl()` or `hsla()` function
+///
+/// # Examples
+///
+/// ```
+/// use chessman::is_css_color;
+/// assert!(is_css_color("#D18A47"));
+/// assert!(is_css_color("rgba(20, 85, 30, 0.5)"));
+/// assert!(!is_css_color("#D18A4"));
+/// ```
+pub fn is_css_color(color: &str) -> bool {
+    let color = color.trim().to_ascii_lowercase();
+    if let Some(hex) = color.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+    if let Some(open) = color.find('(') {
+        let name = &color[..open];
+        let arguments = match color[open + 1..].strip_suffix(')') {
+            Some(s) => s,
+            None => return false,
+        };
+        return match name {
+            "rgb" | "rgba" => valid_arguments(arguments, &["", "%"]),
+            "hsl" | "hsla" => valid_arguments(arguments, &["", "deg", "turn", "rad", "grad", "%"]),
+            _ => false,
+        };
+    }
+    NAMED_COLORS.contains(&color.as_str())
+}
+
+/// Three numbers, optionally followed by an alpha, separated by commas or by spaces and a slash
+fn valid_arguments(arguments: &str, units: &[&str]) -> bool {
+    let parts: Vec<&str> = if arguments.contains(',') {
+        arguments.split(',').map(str::trim).collect()
+    }
+    else {
+        arguments.split(|c: char| c.is_whitespace() || c == '/').filter(|s| !s.is_empty()).collect()
+    };
+    if parts.len() != 3 && parts.len() != 4 {
+        return false;
+    }
+    parts.iter().enumerate().all(|(i, part)| {
+        let units: &[&str] = if i == 3 { &["", "%"] } else { units };
+        units.iter().any(|unit| part.strip_suffix(unit).is_some_and(|number| number.parse::<f32>().is_ok()))
+    })
+}
@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+mod color;
+mod presets;
+
+pub use self::color::is_css_color;
+
+/// Colors, fonts and pieces used by [`ChessRender`](crate::ChessRender)
+///
+/// Themes load from and save to TOML or JSON; missing keys fall back to the brown theme.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub name: String,
+    pub board_white: String,
+    pub board_black: String,
+    /// Third cell shade of hexagonal boards, between the light and dark squares
+    pub board_middle: String,
+    /// Outline of shaped boards
+    pub border_color: String,
+    pub piece_white: String,
+    pub piece_black: String,
+    /// Characters and rims of the red xiangqi pieces, and promoted shogi pieces
+    pub piece_red: String,
+    pub piece_set: PieceSet,
+    pub path_color: String,
+    /// Default color of annotation arrows
+    pub arrow_color: String,
+    pub last_move_color: String,
+    pub selection_color: String,
+    pub check_color: String,
+    /// Color of highlights on custom layers
+    pub highlight_color: String,
+    /// Attacked squares of heatmaps, stronger where more pieces attack
+    pub heat_color: String,
+    /// Squares of heatmaps no piece attacks
+    pub safe_color: String,
+    pub label_font: String,
+    pub label_color: String,
+}
+
+/// How pieces are drawn
+///
+/// Xiangqi and shogi pieces carry their characters with [`Classic`](Self::Classic) and their FEN or SFEN
+/// letters with [`Letters`](Self::Letters). There are no international shogi symbols, the letters are the
+/// only shogi pieces without kanji.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PieceSet {
+    /// The built-in vector figures, xiangqi characters and shogi kanji
+    Classic,
+    /// Discs with the piece letter, easy to read in print and without a CJK font
+    Letters,
+}
+
+/// Errors raised when a theme can not be loaded or saved
+#[derive(Debug)]
+pub enum ThemeError {
+    /// A color is not a valid CSS color
+    InvalidColor {
+        /// Name of the theme field
+        field: &'static str,
+        value: String,
+    },
+    Toml(toml::de::Error),
+    TomlSerialize(toml::ser::Error),
+    Json(serde_json::Error),
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::brown()
+    }
+}
+
+impl Theme {
+    /// Check that every color of the theme is a valid CSS color
+    pub fn validate(&self) -> Result<(), ThemeError> {
+        let colors = [
+            ("board_white", &self.board_white),
+            ("board_black", &self.board_black),
+            ("board_middle", &self.board_middle),
+            ("border_color", &self.border_color),
+            ("piece_white", &self.piece_white),
+            ("piece_black", &self.piece_black),
+            ("piece_red", &self.piece_red),
+            ("path_color", &self.path_color),
+            ("arrow_color", &self.arrow_color),
+            ("last_move_color", &self.last_move_color),
+            ("selection_color", &self.selection_color),
+            ("check_color", &self.check_color),
+            ("highlight_color", &self.highlight_color),
+            ("heat_color", &self.heat_color),
+            ("safe_color", &self.safe_color),
+            ("label_color", &self.label_color),
+        ];
+        for (field, value) in colors.iter() {
+            if !is_css_color(value) {
+                return Err(ThemeError::InvalidColor { field, value: value.to_string() });
+            }
+        }
+        Ok(())
+    }
+    /// Load and validate a theme from TOML
+    pub fn from_toml(text: &str) -> Result<Self, ThemeError> {
+        let theme: Theme = toml::from_str(text)?;
+        theme.validate()?;
+        Ok(theme)
+    }
+    /// Save the theme as TOML
+    pub fn to_toml(&self) -> Result<String, ThemeError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+    /// Load and validate a theme from JSON
+    pub fn from_json(text: &str) -> Result<Self, ThemeError> {
+        let theme: Theme = serde_json::from_str(text)?;
+        theme.validate()?;
+        Ok(theme)
+    }
+    /// Save the theme as JSON
+    pub fn to_json(&self) -> Result<String, ThemeError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+impl From<toml::de::Error> for ThemeError {
+    fn from(e: toml::de::Error) -> Self {
+        ThemeError::Toml(e)
+    }
+}
+
+impl From<toml::ser::Error> for ThemeError {
+    fn from(e: toml::ser::Error) -> Self {
+        ThemeError::TomlSerialize(e)
+    }
+}
+
+impl From<serde_json::Error> for ThemeError {
+    fn from(e: serde_json::Error) -> Self {
+        ThemeError::Json(e)
+    }
+}
+
+impl Display for ThemeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeError::InvalidColor { field, value } => write!(f, "`{}` is not a valid CSS color for `{}`", value, field),
+            ThemeError::Toml(e) => write!(f, "invalid theme TOML: {}", e),
+            ThemeError::TomlSerialize(e) => write!(f, "can not save theme as TOML: {}", e),
+            ThemeError::Json(e) => write!(f, "invalid theme JSON: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeError::InvalidColor { .. } => None,
+            ThemeError::Toml(e) => Some(e),
+            ThemeError::TomlSerialize(e) => Some(e),
+            ThemeError::Json(e) => Some(e),
+        }
+    }
+}
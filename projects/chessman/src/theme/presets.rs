@@ -0,0 +1,89 @@
+use super::*;
+
+impl Theme {
+    /// Names of the built-in themes
+    pub const PRESETS: [&'static str; 4] = ["brown", "blue", "green", "grayscale"];
+
+    /// A built-in theme by name
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "brown" => Some(Self::brown()),
+            "blue" => Some(Self::blue()),
+            "green" => Some(Self::green()),
+            "grayscale" => Some(Self::grayscale()),
+            _ => None,
+        }
+    }
+    /// Wooden brown board, the default
+    pub fn brown() -> Self {
+        Self {
+            name: "brown".to_string(),
+            board_white: "#FFCF9E".to_string(),
+            board_black: "#D18A47".to_string(),
+            board_middle: "#E8AB6F".to_string(),
+            border_color: "#6B4226".to_string(),
+            piece_white: "#FFFFFF".to_string(),
+            piece_black: "#000000".to_string(),
+            piece_red: "#B22222".to_string(),
+            piece_set: PieceSet::Classic,
+            path_color: "#FF0000".to_string(),
+            arrow_color: "#15781BCC".to_string(),
+            last_move_color: "#9BC70069".to_string(),
+            selection_color: "#14551E80".to_string(),
+            check_color: "#FF0000".to_string(),
+            highlight_color: "#FFD70080".to_string(),
+            heat_color: "#FF8C00".to_string(),
+            safe_color: "#2E8B57".to_string(),
+            label_font: "sans-serif".to_string(),
+            label_color: "#404040".to_string(),
+        }
+    }
+    /// Gray blue board
+    pub fn blue() -> Self {
+        Self {
+            name: "blue".to_string(),
+            board_white: "#DEE3E6".to_string(),
+            board_black: "#8CA2AD".to_string(),
+            board_middle: "#B5C3CA".to_string(),
+            border_color: "#4D5F69".to_string(),
+            path_color: "#C0392B".to_string(),
+            arrow_color: "#003088CC".to_string(),
+            last_move_color: "#9BC70069".to_string(),
+            ..Self::brown()
+        }
+    }
+    /// Cream and green tournament board
+    pub fn green() -> Self {
+        Self {
+            name: "green".to_string(),
+            board_white: "#EEEED2".to_string(),
+            board_black: "#769656".to_string(),
+            board_middle: "#B2C294".to_string(),
+            border_color: "#4B6336".to_string(),
+            arrow_color: "#FFAA00CC".to_string(),
+            last_move_color: "#F6F66980".to_string(),
+            ..Self::brown()
+        }
+    }
+    /// Light grays only, for printing
+    pub fn grayscale() -> Self {
+        Self {
+            name: "grayscale".to_string(),
+            board_white: "#FFFFFF".to_string(),
+            board_black: "#C8C8C8".to_string(),
+            board_middle: "#E4E4E4".to_string(),
+            border_color: "#000000".to_string(),
+            path_color: "#000000".to_string(),
+            piece_red: "#707070".to_string(),
+            arrow_color: "#404040CC".to_string(),
+            last_move_color: "#80808060".to_string(),
+            selection_color: "#40404060".to_string(),
+            check_color: "#000000".to_string(),
+            highlight_color: "#A0A0A080".to_string(),
+            heat_color: "#000000".to_string(),
+            safe_color: "#FFFFFF".to_string(),
+            label_color: "#000000".to_string(),
+            ..Self::brown()
+        }
+    }
+}
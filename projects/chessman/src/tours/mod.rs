@@ -0,0 +1,177 @@
+use crate::BoardShape;
+
+/// The eight jumps of a knight
+const KNIGHT_MOVES: [(isize, isize); 8] = [(1, 2), (2, 1), (2, -1), (1, -2), (-1, -2), (-2, -1), (-2, 1), (-1, 2)];
+
+/// A knight's tour: every square of a board visited exactly once
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KnightTour {
+    /// The board the knight travels
+    pub shape: BoardShape,
+    /// Visited squares in order
+    pub steps: Vec<(isize, isize)>,
+    /// The last square is a knight move away from the first
+    pub closed: bool,
+}
+
+/// Search for knight's tours, following Warnsdorff's rule and backtracking when it runs into a dead end
+#[derive(Clone, Debug)]
+pub struct KnightTourSolver {
+    /// The board the knight travels
+    pub shape: BoardShape,
+    /// Square to start from, the first square of the top row by default
+    pub start: Option<(isize, isize)>,
+    /// Require the tour to end a knight move away from its start
+    pub closed: bool,
+    /// Give up after this many backtracking steps
+    pub backtrack_limit: usize,
+}
+
+/// Find an open knight's tour of a `width` × `height` board starting from the corner
+///
+/// # Examples
+///
+/// ```
+/// use chessman::knights_tour;
+/// assert_eq!(knights_tour(8, 8).unwrap().steps.len(), 64);
+/// assert!(knights_tour(4, 4).is_none());
+/// ```
+pub fn knights_tour(width: usize, height: usize) -> Option<KnightTour> {
+    KnightTourSolver::new(width, height).solve()
+}
+
+/// Whether a knight jumps from `from` to `to` in one move
+pub fn is_knight_move(from: (isize, isize), to: (isize, isize)) -> bool {
+    let (dx, dy) = ((to.0 - from.0).abs(), (to.1 - from.1).abs());
+    (dx, dy) == (1, 2) || (dx, dy) == (2, 1)
+}
+
+impl KnightTour {
+    /// Check that the steps visit every square once with knight moves
+    pub fn is_valid(&self) -> bool {
+        let mut visited = vec![false; self.shape.width() * self.shape.height()];
+        for &(x, y) in &self.steps {
+            if !self.shape.contains(x, y) {
+                return false;
+            }
+            let index = y as usize * self.shape.width() + x as usize;
+            if visited[index] {
+                return false;
+            }
+            visited[index] = true;
+        }
+        let jumps = self.steps.windows(2).all(|pair| is_knight_move(pair[0], pair[1]));
+        let closing = match (self.steps.first(), self.steps.last()) {
+            (Some(&first), Some(&last)) => !self.closed || is_knight_move(last, first),
+            _ => false,
+        };
+        self.steps.len() == self.shape.count() && jumps && closing
+    }
+}
+
+impl KnightTourSolver {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::with_shape(BoardShape::rectangle(width, height))
+    }
+    /// Tour the playable squares of a shaped board
+    pub fn with_shape(shape: BoardShape) -> Self {
+        Self { shape, start: None, closed: false, backtrack_limit: 1_000_000 }
+    }
+    /// Find one tour, or `None` if there is none or the search gives up
+    pub fn solve(&self) -> Option<KnightTour> {
+        let squares = self.shape.count();
+        let start = self.start.or_else(|| self.shape.squares().next())?;
+        // a knight changes square color on every move, so a closed tour needs as many light as dark squares
+        let light = self.shape.squares().filter(|(x, y)| (x + y) % 2 == 0).count();
+        if !self.contains(start) || (self.closed && 2 * light != squares) {
+            return None;
+        }
+        let mut visited = vec![false; self.shape.width() * self.shape.height()];
+        visited[self.index(start)] = true;
+        let mut steps = vec![start];
+        let mut candidates = vec![self.candidates(start, &visited)];
+        let mut backtracks = 0;
+        while let Some(next) = candidates.last_mut() {
+            let last = steps[steps.len() - 1];
+            if steps.len() == squares {
+                if !self.closed {
+                    return Some(KnightTour { shape: self.shape.clone(), steps, closed: false });
+                }
+                if let Some(steps) = self.close(&steps) {
+                    return Some(KnightTour { shape: self.shape.clone(), steps, closed: true });
+                }
+            }
+            match next.pop() {
+                Some(square) => {
+                    visited[self.index(square)] = true;
+                    steps.push(square);
+                    candidates.push(self.candidates(square, &visited));
+                }
+                None => {
+                    backtracks += 1;
+                    if backtracks > self.backtrack_limit {
+                        return None;
+                    }
+                    candidates.pop();
+                    visited[self.index(last)] = false;
+                    steps.pop();
+                }
+            }
+        }
+        None
+    }
+    /// Turn an open tour into a closed one with the same start by Pósa rotations
+    ///
+    /// When the end of the tour is a knight move away from step `i`, reversing the steps after `i` gives
+    /// another tour ending on step `i + 1`, repeat until the end is a knight move away from the start.
+    fn close(&self, steps: &[(isize, isize)]) -> Option<Vec<(isize, isize)>> {
+        let mut steps = steps.to_vec();
+        let length = steps.len();
+        let mut position = vec![0; self.shape.width() * self.shape.height()];
+        for (i, &square) in steps.iter().enumerate() {
+            position[self.index(square)] = i;
+        }
+        for round in 0..4 * length {
+            let end = steps[length - 1];
+            if is_knight_move(end, steps[0]) {
+                return Some(steps);
+            }
+            let pivots: Vec<usize> =
+                self.jumps(end).map(|square| position[self.index(square)]).filter(|&i| i + 2 < length).collect();
+            if pivots.is_empty() {
+                return None;
+            }
+            let pivot = match pivots.iter().find(|&&i| is_knight_move(steps[i + 1], steps[0])) {
+                Some(&i) => i,
+                None => pivots[round % pivots.len()],
+            };
+            steps[pivot + 1..].reverse();
+            for i in pivot + 1..length {
+                position[self.index(steps[i])] = i;
+            }
+        }
+        None
+    }
+    fn contains(&self, (x, y): (isize, isize)) -> bool {
+        self.shape.contains(x, y)
+    }
+    fn index(&self, (x, y): (isize, isize)) -> usize {
+        y as usize * self.shape.width() + x as usize
+    }
+    fn jumps(&self, (x, y): (isize, isize)) -> impl Iterator<Item = (isize, isize)> + '_ {
+        KNIGHT_MOVES.iter().map(move |(dx, dy)| (x + dx, y + dy)).filter(move |&square| self.contains(square))
+    }
+    /// Unvisited squares a knight can reach, the most promising last
+    ///
+    /// Warnsdorff's rule prefers the square with the fewest onward moves, ties go to the square farther from the center.
+    fn candidates(&self, square: (isize, isize), visited: &[bool]) -> Vec<(isize, isize)> {
+        let mut candidates: Vec<_> = self.jumps(square).filter(|&next| !visited[self.index(next)]).collect();
+        let center = (self.shape.width() as isize - 1, self.shape.height() as isize - 1);
+        candidates.sort_by_key(|&next| {
+            let degree = self.jumps(next).filter(|&onward| !visited[self.index(onward)]).count();
+            let distance = (2 * next.0 - center.0).pow(2) + (2 * next.1 - center.1).pow(2);
+            std::cmp::Reverse((degree, -distance))
+        });
+        candidates
+    }
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+impl Display for XiangqiPosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in 0..10 {
+            if y != 0 {
+                write!(f, "/")?;
+            }
+            let mut empty = 0;
+            for x in 0..9 {
+                match self.piece_at(x, y) {
+                    Some(piece) => {
+                        if empty != 0 {
+                            write!(f, "{}", empty)?;
+                            empty = 0;
+                        }
+                        write!(f, "{}", piece.fen_char())?;
+                    }
+                    None => empty += 1,
+                }
+            }
+            if empty != 0 {
+                write!(f, "{}", empty)?;
+            }
+        }
+        let side = match self.side_to_move {
+            PieceColor::White => 'w',
+            PieceColor::Black => 'b',
+        };
+        write!(f, " {} - - {} {}", side, self.halfmove_clock, self.fullmove_number)
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{FenError, PieceColor};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+mod display;
+
+/// Kinds of xiangqi pieces
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum XiangqiKind {
+    General,
+    Advisor,
+    Elephant,
+    Horse,
+    Chariot,
+    Cannon,
+    Soldier,
+}
+
+/// A xiangqi piece, red plays the [`PieceColor::White`] side and moves first
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct XiangqiPiece {
+    pub kind: XiangqiKind,
+    pub color: PieceColor,
+}
+
+/// A xiangqi position decoded from WXF FEN
+///
+/// The board has 9 files and 10 ranks of intersections, `x` runs from the left file and `y`
+/// from the black side at the top down to the red side.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XiangqiPosition {
+    points: [Option<XiangqiPiece>; 90],
+    /// The side to move, red is [`PieceColor::White`]
+    pub side_to_move: PieceColor,
+    /// Half moves since the last capture
+    pub halfmove_clock: u32,
+    /// Number of the full move, starting at 1
+    pub fullmove_number: u32,
+}
+
+impl XiangqiKind {
+    /// All kinds, general first
+    pub const ALL: [XiangqiKind; 7] = [
+        XiangqiKind::General,
+        XiangqiKind::Advisor,
+        XiangqiKind::Elephant,
+        XiangqiKind::Horse,
+        XiangqiKind::Chariot,
+        XiangqiKind::Cannon,
+        XiangqiKind::Soldier,
+    ];
+
+    /// Read a WXF letter, ignoring case; `e` and `h` are accepted for elephants and horses
+    pub fn from_fen_char(c: char) -> Option<Self> {
+        match c.to_ascii_lowercase() {
+            'k' => Some(XiangqiKind::General),
+            'a' => Some(XiangqiKind::Advisor),
+            'b' | 'e' => Some(XiangqiKind::Elephant),
+            'n' | 'h' => Some(XiangqiKind::Horse),
+            'r' => Some(XiangqiKind::Chariot),
+            'c' => Some(XiangqiKind::Cannon),
+            'p' => Some(XiangqiKind::Soldier),
+            _ => None,
+        }
+    }
+    /// The lowercase WXF letter
+    pub fn fen_char(self) -> char {
+        match self {
+            XiangqiKind::General => 'k',
+            XiangqiKind::Advisor => 'a',
+            XiangqiKind::Elephant => 'b',
+            XiangqiKind::Horse => 'n',
+            XiangqiKind::Chariot => 'r',
+            XiangqiKind::Cannon => 'c',
+            XiangqiKind::Soldier => 'p',
+        }
+    }
+    /// The traditional character, which differs between the red and the black side
+    pub fn character(self, color: PieceColor) -> char {
+        let (red, black) = match self {
+            XiangqiKind::General => ('帥', '將'),
+            XiangqiKind::Advisor => ('仕', '士'),
+            XiangqiKind::Elephant => ('相', '象'),
+            XiangqiKind::Horse => ('傌', '馬'),
+            XiangqiKind::Chariot => ('俥', '車'),
+            XiangqiKind::Cannon => ('炮', '砲'),
+            XiangqiKind::Soldier => ('兵', '卒'),
+        };
+        match color {
+            PieceColor::White => red,
+            PieceColor::Black => black,
+        }
+    }
+}
+
+impl XiangqiPiece {
+    pub fn new(kind: XiangqiKind, color: PieceColor) -> Self {
+        Self { kind, color }
+    }
+    /// Read a WXF letter, uppercase for red and lowercase for black
+    pub fn from_fen_char(c: char) -> Option<Self> {
+        let color = if c.is_ascii_uppercase() { PieceColor::White } else { PieceColor::Black };
+        XiangqiKind::from_fen_char(c).map(|kind| Self { kind, color })
+    }
+    /// The WXF letter, uppercase for red
+    pub fn fen_char(self) -> char {
+        match self.color {
+            PieceColor::White => self.kind.fen_char().to_ascii_uppercase(),
+            PieceColor::Black => self.kind.fen_char(),
+        }
+    }
+    /// The traditional character
+    pub fn character(self) -> char {
+        self.kind.character(self.color)
+    }
+}
+
+impl Default for XiangqiPosition {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl XiangqiPosition {
+    /// FEN of the starting position
+    pub const STARTING_FEN: &'static str = "rnbakabnr/9/1c5c1/p1p1p1p1p/9/9/P1P1P1P1P/1C5C1/9/RNBAKABNR w - - 0 1";
+
+    /// A board without any pieces, red to move
+    pub fn empty() -> Self {
+        Self { points: [None; 90], side_to_move: PieceColor::White, halfmove_clock: 0, fullmove_number: 1 }
+    }
+    /// The starting position
+    pub fn starting() -> Self {
+        Self::from_str(Self::STARTING_FEN).expect("starting position is valid")
+    }
+    /// The piece on point `(x, y)`, if any
+    pub fn piece_at(&self, x: isize, y: isize) -> Option<XiangqiPiece> {
+        if (0..9).contains(&x) && (0..10).contains(&y) {
+            self.points[(y * 9 + x) as usize]
+        }
+        else {
+            None
+        }
+    }
+    /// Put a piece on point `(x, y)`, or clear it with `None`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the point is outside the board.
+    pub fn set_piece(&mut self, x: isize, y: isize, piece: Option<XiangqiPiece>) {
+        assert!((0..9).contains(&x) && (0..10).contains(&y), "point ({}, {}) is outside the board", x, y);
+        self.points[(y * 9 + x) as usize] = piece;
+    }
+    /// All pieces on the board with their points, black side first
+    pub fn pieces(&self) -> impl Iterator<Item = (isize, isize, XiangqiPiece)> + '_ {
+        self.points.iter().enumerate().filter_map(|(i, piece)| piece.map(|p| (i as isize % 9, i as isize / 9, p)))
+    }
+}
+
+impl FromStr for XiangqiPosition {
+    type Err = FenError;
+
+    fn from_str(fen: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if fields.len() > 6 {
+            return Err(FenError::TooManyFields(fields.len()));
+        }
+        let mut position = XiangqiPosition::empty();
+        let board = fields.first().ok_or(FenError::MissingBoard)?;
+        let ranks: Vec<&str> = board.split('/').collect();
+        if ranks.len() != 10 {
+            return Err(FenError::RankCount { ranks: ranks.len(), expected: 10 });
+        }
+        for (y, rank) in ranks.iter().enumerate() {
+            let mut x = 0;
+            for c in rank.chars() {
+                match c.to_digit(10) {
+                    Some(skip @ 1..=9) => x += skip as usize,
+                    Some(_) => return Err(FenError::InvalidPiece(c)),
+                    None => {
+                        let piece = XiangqiPiece::from_fen_char(c).ok_or(FenError::InvalidPiece(c))?;
+                        if x < 9 {
+                            position.points[y * 9 + x] = Some(piece);
+                        }
+                        x += 1;
+                    }
+                }
+            }
+            if x != 9 {
+                return Err(FenError::RankLength { rank: 10 - y, files: x, expected: 9 });
+            }
+        }
+        if let Some(side) = fields.get(1) {
+            position.side_to_move = match *side {
+                "w" | "r" => PieceColor::White,
+                "b" => PieceColor::Black,
+                _ => return Err(FenError::InvalidSideToMove(side.to_string())),
+            };
+        }
+        // xiangqi has neither castling nor en passant, the fields are kept for FEN compatibility
+        match fields.get(2) {
+            Some(&"-") | None => {}
+            Some(castling) => return Err(FenError::InvalidCastling(castling.to_string())),
+        }
+        match fields.get(3) {
+            Some(&"-") | None => {}
+            Some(en_passant) => return Err(FenError::InvalidEnPassant(en_passant.to_string())),
+        }
+        if let Some(counter) = fields.get(4) {
+            position.halfmove_clock = counter.parse().map_err(|_| FenError::InvalidCounter(counter.to_string()))?;
+        }
+        if let Some(counter) = fields.get(5) {
+            position.fullmove_number = counter.parse().map_err(|_| FenError::InvalidCounter(counter.to_string()))?;
+        }
+        Ok(position)
+    }
+}
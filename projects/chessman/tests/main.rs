@@ -0,0 +1,518 @@
+use chessman::{
+    file_label, is_css_color, AnimationTiming, Annotations, Arrow, AsciiBackend, AttackHeatmap, BoardShape, ChessMove,
+    ChessPiece, ChessPosition, ChessRender, CoordinateStyle, FenError, Gallery, HexBoard, HexVariant, Highlight,
+    HighlightLayer, HighlightStyle, HtmlViewer, KnightTourSolver, MoveError, Orientation, PieceColor, PieceKind, PieceSet,
+    ShogiKind, ShogiPiece, ShogiPosition, SquareStatus, SvgBackend, TerminalBackend, Theme, ThemeError, XiangqiKind,
+    XiangqiPiece, XiangqiPosition,
+};
+
+#[test]
+fn test_draw_pieces() {
+    let render = ChessRender::default();
+    let mut document = render.document(6.0, 2.0);
+    for (x, kind) in PieceKind::ALL.iter().enumerate() {
+        for (y, color) in [PieceColor::White, PieceColor::Black].iter().enumerate() {
+            document = document.add(render.draw_square(x as isize, y as isize));
+            document = document.add(render.draw_piece(*kind, *color, x as isize, y as isize));
+        }
+    }
+    let svg = document.to_string();
+    assert_eq!(svg.matches("<g ").count(), 12);
+    assert!(svg.contains("translate(500 100) scale(2.2222223)"));
+}
+
+#[test]
+fn test_render_fen() {
+    let render = ChessRender { show_side_to_move: true, show_castling: true, ..Default::default() };
+    let document = render.render_fen(ChessPosition::STARTING_FEN).unwrap();
+    let svg = document.to_string();
+    assert!(svg.starts_with("<svg viewBox=\"0 0 850 800\""));
+    assert_eq!(svg.matches("<rect ").count(), 64);
+    assert_eq!(svg.matches("<g stroke").count(), 32);
+
+    let position: ChessPosition = "r3k2r/8/8/8/8/8/8/R3K2R b Kq e3 5 20".parse().unwrap();
+    assert_eq!(position.to_string(), "r3k2r/8/8/8/8/8/8/R3K2R b Kq e3 5 20");
+    assert_eq!(position.piece_at(4, 7), Some(ChessPiece::new(PieceKind::King, PieceColor::White)));
+    assert_eq!(position.en_passant, Some((4, 5)));
+    let mut position = position;
+    position.en_passant = Some((-1, 9));
+    assert_eq!(position.to_string(), "r3k2r/8/8/8/8/8/8/R3K2R b Kq (-1, 9) 5 20");
+}
+
+#[test]
+fn test_render_fen_errors() {
+    let render = ChessRender::default();
+    assert_eq!(render.render_fen("").unwrap_err(), FenError::MissingBoard);
+    assert_eq!(render.render_fen("8/8/8/8/8/8/8 w").unwrap_err(), FenError::RankCount { ranks: 7, expected: 8 });
+    assert_eq!(render.render_fen("8/8/8/8/8/8/8 w").unwrap_err().to_string(), "FEN describes 7 ranks, expected 8");
+    assert_eq!(render.render_fen("8/8/8/8/8/8/8/9").unwrap_err(), FenError::InvalidPiece('9'));
+    assert_eq!(
+        render.render_fen("8/8/8/8/8/8/8/ppppppppp").unwrap_err(),
+        FenError::RankLength { rank: 1, files: 9, expected: 8 }
+    );
+    assert_eq!(render.render_fen("8/8/8/8/8/8/8/8 x").unwrap_err(), FenError::InvalidSideToMove("x".to_string()));
+    assert_eq!(render.render_fen("8/8/8/8/8/8/8/8 w KK").unwrap_err(), FenError::InvalidCastling("KK".to_string()));
+    assert_eq!(render.render_fen("8/8/8/8/8/8/8/8 w - e4").unwrap_err(), FenError::InvalidEnPassant("e4".to_string()));
+}
+
+#[test]
+fn test_coordinates() {
+    assert_eq!(file_label(0), "a");
+    assert_eq!(file_label(25), "z");
+    assert_eq!(file_label(26), "aa");
+    let render = ChessRender { coordinates: CoordinateStyle::Outside, ..Default::default() };
+    let document = render.document(10.0, 10.0).add(render.draw_coordinates(10, 10));
+    let svg = document.to_string();
+    assert!(svg.starts_with("<svg viewBox=\"-50 -50 1100 1100\""));
+    assert!(svg.contains(">\nj\n</text>"));
+    assert!(svg.contains(">\n10\n</text>"));
+}
+
+#[test]
+fn test_orientation() {
+    let render = ChessRender { orientation: Orientation::Flipped, ..Default::default() };
+    assert_eq!(render.square_center(0, 0), (-50.0, -50.0));
+    assert_eq!(render.view_box(8.0, 8.0), (-800.0, -800.0, 800.0, 800.0));
+    let line = render.draw_path(0, 0, 1, 2).to_string();
+    assert!(line.contains("x1=\"-50\" x2=\"-150\" y1=\"-50\" y2=\"-250\""));
+
+    let render = ChessRender { orientation: Orientation::Clockwise, ..Default::default() };
+    assert_eq!(render.view_box(8.0, 4.0), (-400.0, 0.0, 400.0, 800.0));
+    assert_eq!(render.square_center(0, 0), (-50.0, 50.0));
+}
+
+#[test]
+fn test_arrows() {
+    let render = ChessRender::default();
+    assert!(Arrow::new((6, 7), (5, 5)).is_knight_move());
+    let arrows = [Arrow::new((4, 6), (4, 4)), Arrow::new((6, 7), (5, 5)).with_color("#882020"), Arrow::new((3, 6), (3, 4))];
+    let svg = render.draw_arrows(&arrows).to_string();
+    assert_eq!(svg.matches("<marker ").count(), 2);
+    assert!(svg.contains("id=\"arrowhead-_882020\""));
+    // the shaft stops one head length before the edge of e4
+    assert!(svg.contains("d=\"M450,650 L450,545\""));
+    // knight arrow bends on g3
+    assert!(svg.contains("d=\"M650,750 L650,550 L642.5,550\""));
+}
+
+#[test]
+fn test_highlights() {
+    let render = ChessRender::default();
+    let mut annotations = Annotations::default();
+    annotations
+        .highlight(HighlightLayer::Custom("plan".to_string()), Highlight::new(2, 2, HighlightStyle::Dot))
+        .check(4, 0)
+        .last_move((4, 6), (4, 4))
+        .select(6, 7)
+        .arrow(Arrow::new((6, 7), (5, 5)));
+    assert_eq!(annotations.layer(&HighlightLayer::LastMove).len(), 2);
+    let order: Vec<_> = annotations.layers().map(|(layer, _)| layer.clone()).collect();
+    assert_eq!(
+        order,
+        [
+            HighlightLayer::LastMove,
+            HighlightLayer::Selection,
+            HighlightLayer::Check,
+            HighlightLayer::Custom("plan".to_string())
+        ]
+    );
+    let svg = render.render_annotated(&ChessPosition::starting(), &annotations).to_string();
+    let highlights = svg.find(&render.theme.last_move_color).unwrap();
+    assert!(highlights < svg.find("<g stroke").unwrap());
+    assert!(svg.find("<marker").unwrap() > svg.rfind("<g stroke").unwrap());
+}
+
+#[test]
+fn test_play_moves() {
+    let mut position = ChessPosition::starting();
+    for uci in ["e2e4", "d7d5", "e4d5", "e7e5", "d5e6", "f8c5", "g1f3", "g8f6", "f1e2", "e8g8"].iter() {
+        position.play(&uci.parse().unwrap()).unwrap();
+    }
+    assert_eq!(position.to_string(), "rnbq1rk1/ppp2ppp/4Pn2/2b5/8/5N2/PPPPBPPP/RNBQK2R w KQ - 5 6");
+    let promotion: ChessMove = "e7e8n".parse().unwrap();
+    assert_eq!(promotion.promotion, Some(PieceKind::Knight));
+    assert_eq!(promotion.to_string(), "e7e8n");
+    assert_eq!("e2e9".parse::<ChessMove>(), Err(MoveError::Notation("e2e9".to_string())));
+    assert_eq!(position.play(&"e5e4".parse().unwrap()), Err(MoveError::EmptySquare("e5".to_string())));
+    // unchecked moves off the board are refused before anything changes
+    let before = position.clone();
+    let error = position.play(&ChessMove::new((4, 6), (4, 8))).unwrap_err();
+    assert_eq!(error, MoveError::OutOfBoard((4, 8)));
+    assert_eq!(error.to_string(), "square (4, 8) is outside of the board");
+    assert_eq!(position.play(&ChessMove::new((-1, 6), (0, 5))), Err(MoveError::OutOfBoard((-1, 6))));
+    assert_eq!(ChessMove::new((-1, 6), (0, 5)).to_string(), "(-1, 6)a3");
+    assert_eq!(position, before);
+    let viewer = ChessRender::default().html_moves("Off board", &before, &[ChessMove::new((4, 6), (4, 8))]);
+    assert_eq!(viewer.err(), Some(MoveError::OutOfBoard((4, 8))));
+    // a diagonal pawn move onto an empty square is only en passant on the en passant square
+    let mut position: ChessPosition = "4k3/8/8/8/8/8/3RP3/4K3 w - - 0 1".parse().unwrap();
+    let played = position.play(&"e2d3".parse().unwrap()).unwrap();
+    assert_eq!(played.captured, None);
+    assert_eq!(position.to_string(), "4k3/8/8/8/8/3P4/3R4/4K3 b - - 0 1");
+}
+
+#[test]
+fn test_animation() {
+    let render = ChessRender::default();
+    let position: ChessPosition = "4k3/P7/8/8/8/8/8/4K2R w K - 0 1".parse().unwrap();
+    let moves: Vec<ChessMove> = ["e1g1", "a7a8q"].iter().map(|uci| uci.parse().unwrap()).collect();
+    let svg = render.animate_moves(&position, &moves, &AnimationTiming::default()).unwrap().to_string();
+    // the king and the rook castle together, then the pawn is swapped for a queen
+    assert!(svg.contains(r#"keyTimes="0;0.2;0.4;1" type="translate" values="0 0;0 0;200 0;200 0""#));
+    assert!(svg.contains(r#"keyTimes="0;0.2;0.4;1" type="translate" values="0 0;0 0;-200 0;-200 0""#));
+    assert!(svg.contains(r#"keyTimes="0;0.8" values="1;0""#));
+    assert!(svg.contains(r#"keyTimes="0;0.8" values="0;1""#));
+    let timing = AnimationTiming { move_duration: 1.0, pause: 0.0, repeat: true };
+    let path = render.animate_path(&[(0, 0), (1, 2), (2, 0)], &timing).to_string();
+    assert_eq!(path.matches("<line").count(), 2);
+    assert_eq!(path.matches("<circle").count(), 3);
+    assert!(path.contains(r#"dur="2s" keyTimes="0;0.5;1;1" repeatCount="indefinite" values="150;150;250;250""#));
+}
+
+#[test]
+fn test_themes() {
+    for name in Theme::PRESETS.iter() {
+        let theme = Theme::preset(name).unwrap();
+        theme.validate().unwrap();
+        assert_eq!(Theme::from_toml(&theme.to_toml().unwrap()).unwrap(), theme);
+        assert_eq!(Theme::from_json(&theme.to_json().unwrap()).unwrap(), theme);
+    }
+    let theme = Theme::from_toml("name = \"mine\"\nboard_black = \"rgb(100, 120, 140)\"\npiece_set = \"letters\"").unwrap();
+    assert_eq!(theme.piece_set, PieceSet::Letters);
+    assert_eq!(theme.board_white, Theme::brown().board_white);
+    match Theme::from_json("{\"board_white\": \"#12345\"}") {
+        Err(ThemeError::InvalidColor { field, .. }) => assert_eq!(field, "board_white"),
+        other => panic!("{:?}", other),
+    }
+    assert!(is_css_color("hsl(120deg 50% 50% / 0.5)"));
+    assert!(is_css_color("RebeccaPurple"));
+    assert!(!is_css_color("rgb(1, 2)"));
+
+    let render = ChessRender::with_theme(theme);
+    assert!(render.render_fen(ChessPosition::STARTING_FEN).unwrap().to_string().contains("rgb(100, 120, 140)"));
+}
+
+#[test]
+#[cfg(feature = "png")]
+fn test_render_png() {
+    let render = ChessRender { coordinates: CoordinateStyle::Outside, ..Default::default() };
+    let document = render.render_fen(ChessPosition::STARTING_FEN).unwrap();
+    let pixmap = chessman::Rasterizer::default().render_pixmap(&document, 360).unwrap();
+    assert_eq!((pixmap.width(), pixmap.height()), (360, 360));
+    let png = chessman::render_png(&document, 360).unwrap();
+    assert!(png.starts_with(b"\x89PNG"));
+}
+
+#[test]
+fn test_knights_tour() {
+    for &(width, height, closed) in
+        [(8, 8, false), (8, 8, true), (5, 5, false), (3, 10, true), (6, 5, true), (20, 20, true)].iter()
+    {
+        let mut solver = KnightTourSolver::new(width, height);
+        solver.closed = closed;
+        solver.start = Some((2, 2));
+        let tour = solver.solve().unwrap();
+        assert!(tour.is_valid());
+        assert_eq!(tour.closed, closed);
+        assert_eq!(tour.steps[0], (2, 2));
+    }
+    for &(width, height, closed) in [(4, 4, false), (3, 3, false), (5, 5, true), (4, 6, true)].iter() {
+        let mut solver = KnightTourSolver::new(width, height);
+        solver.closed = closed;
+        assert_eq!(solver.solve(), None);
+    }
+    let mut solver = KnightTourSolver::new(5, 6);
+    solver.closed = true;
+    let svg = ChessRender::default().render_tour(&solver.solve().unwrap()).to_string();
+    assert_eq!(svg.matches("<line").count(), 30);
+    assert_eq!(svg.matches("<text").count(), 30);
+}
+
+#[test]
+fn test_board_shapes() {
+    let mut shape = BoardShape::rectangle(4, 4);
+    shape.remove(1, 1).remove(2, 2).remove(9, 9);
+    assert_eq!(shape.count(), 14);
+    assert!(!shape.contains(-1, 0));
+    // the holes touch at a corner and make one loop
+    assert_eq!(
+        shape.outline(),
+        vec![vec![(0, 0), (4, 0), (4, 4), (0, 4)], vec![(1, 1), (1, 2), (2, 2), (2, 3), (3, 3), (3, 2), (2, 2), (2, 1)]]
+    );
+    let render = ChessRender::default();
+    let svg = render.render_shape(&shape).to_string();
+    assert_eq!(svg.matches("<rect").count(), 14);
+    assert!(svg.contains(
+        r#"d="M0,0 L400,0 L400,400 L0,400 z M100,100 L100,200 L200,200 L200,300 L300,300 L300,200 L200,200 L200,100 z""#
+    ));
+    assert!(!render.render_shape(&BoardShape::rectangle(3, 3)).to_string().contains("<path"));
+    let ring = BoardShape::from_rows(&[
+        "..####..", ".######.", "########", "###..###", "###..###", "########", ".######.", "..####..",
+    ]);
+    let mut solver = KnightTourSolver::with_shape(ring);
+    solver.closed = true;
+    let tour = solver.solve().unwrap();
+    assert!(tour.is_valid());
+    assert_eq!(tour.steps[0], (2, 0));
+    assert_eq!(render.render_tour(&tour).to_string().matches("<rect").count(), 48);
+}
+
+#[test]
+fn test_hex_boards() {
+    let mut board = HexVariant::Glinski.board();
+    assert_eq!(board.count(), 91);
+    assert_eq!(HexVariant::McCooey.board(), board);
+    assert_eq!(HexVariant::Shafran.board().count(), 70);
+    for (q, r) in board.cells() {
+        assert!(board.neighbours(q, r).all(|(nq, nr)| HexBoard::cell_shade(nq, nr) != HexBoard::cell_shade(q, r)));
+        assert_eq!(board.parse_cell(&board.cell_name(q, r).unwrap()), Some((q, r)));
+    }
+    assert_eq!(board.cell_name(0, 0).unwrap(), "f6");
+    assert_eq!(board.cell_name(5, -5).unwrap(), "l6");
+    assert_eq!(board.parse_cell("a7"), None);
+    assert_eq!(board.parse_cell("j1"), None);
+    // past `z` files get two letters
+    let large = HexBoard::hexagon(13);
+    for (q, r) in large.cells() {
+        assert_eq!(large.parse_cell(&large.cell_name(q, r).unwrap()), Some((q, r)));
+    }
+    assert_eq!(large.cell_name(13, 0).unwrap(), "ab1");
+    let labelled = ChessRender { coordinates: CoordinateStyle::Outside, ..ChessRender::hex() };
+    assert!(labelled.render_hex(&HexBoard::hexagon(64)).to_string().contains("\nab\n</text>"));
+    board.set_piece(1, 4, Some(ChessPiece::new(PieceKind::King, PieceColor::White)));
+    let mut annotations = Annotations::default();
+    annotations.select(1, 4).arrow(Arrow::new((1, 4), (2, 1)));
+    let render = ChessRender::hex();
+    let svg = render.render_hex_annotated(&board, &annotations).to_string();
+    assert!(svg.starts_with(r#"<svg viewBox="-490.74774 -550 981.4955 1100""#));
+    assert_eq!(svg.matches(&format!(r#"fill="{}""#, render.theme.board_middle)).count(), 31);
+    // arrows on hexagons are never bent
+    assert!(svg.contains(r#"d="M86.60255,450 L141.15488,292.521""#));
+    let (x, y) = render.square_center(-5, 5);
+    assert_eq!((x.round(), y), (-433.0, 250.0));
+    let path = render.draw_path(0, 0, 1, 0).to_string();
+    assert_eq!(path, r##"<line stroke="#FF0000" stroke-width="6" x1="0" x2="86.60255" y1="0" y2="50"/>"##);
+}
+
+#[test]
+fn test_xiangqi() {
+    let position = XiangqiPosition::starting();
+    assert_eq!(position.to_string(), XiangqiPosition::STARTING_FEN);
+    assert_eq!(position.pieces().count(), 32);
+    assert_eq!(position.piece_at(4, 9), Some(XiangqiPiece::new(XiangqiKind::General, PieceColor::White)));
+    assert_eq!(position.piece_at(7, 2).unwrap().character(), '砲');
+    let alternative: XiangqiPosition = "rheakaehr/9/1c5c1/p1p1p1p1p/9/9/P1P1P1P1P/1C5C1/9/RHEAKAEHR r".parse().unwrap();
+    assert_eq!(alternative, position);
+    assert_eq!(ChessPosition::STARTING_FEN.parse::<XiangqiPosition>(), Err(FenError::RankCount { ranks: 8, expected: 10 }));
+    assert_eq!(
+        "9/9/9/9/9/9/9/9/9/8 w".parse::<XiangqiPosition>(),
+        Err(FenError::RankLength { rank: 1, files: 8, expected: 9 })
+    );
+    assert_eq!(
+        "9/9/9/9/9/9/9/9/9/8 w".parse::<XiangqiPosition>().unwrap_err().to_string(),
+        "rank 1 describes 8 files, expected 9"
+    );
+    assert_eq!("9/9/9/9/9/9/9/9/9/9 w KQ".parse::<XiangqiPosition>(), Err(FenError::InvalidCastling("KQ".to_string())));
+    let render = ChessRender::default();
+    let svg = render.render_xiangqi(&position).to_string();
+    assert!(svg.starts_with(r#"<svg viewBox="0 0 900 1000""#));
+    assert!(svg.contains("楚　河") && svg.contains('帥') && svg.contains('將'));
+    assert_eq!(svg.matches("<circle").count(), 64);
+    // palace diagonals
+    assert!(svg.contains(r#"d="M350,50 L550,250""#) && svg.contains(r#"d="M550,750 L350,950""#));
+}
+
+#[test]
+fn test_shogi() {
+    let position = ShogiPosition::starting();
+    assert_eq!(position.to_string(), ShogiPosition::STARTING_SFEN);
+    assert_eq!(position.piece_at(4, 8), Some(ShogiPiece::new(ShogiKind::King, PieceColor::Black)));
+    assert_eq!(position.piece_at(4, 0).unwrap().kanji(), '王');
+    let sfen = "8l/1l+R2P3/p2pBG1pp/kps1p4/Nn1P2G2/P1P1P2PP/1PS6/1KSG3+r1/LN2+p3L w Sbgn3p 124";
+    let position: ShogiPosition = sfen.parse().unwrap();
+    assert_eq!(position.to_string(), sfen);
+    assert_eq!(position.piece_at(2, 1).unwrap().kanji(), '龍');
+    assert_eq!(position.hand(PieceColor::Black).collect::<Vec<_>>(), [(ShogiKind::Silver, 1)]);
+    assert_eq!(position.hand(PieceColor::White).last(), Some((ShogiKind::Pawn, 3)));
+    assert_eq!("9/9/9/9/9/9/9/9/+G8 b".parse::<ShogiPosition>(), Err(FenError::InvalidPiece('G')));
+    assert_eq!("9/9/9/9/9/9/9/9/9 b 2".parse::<ShogiPosition>(), Err(FenError::InvalidHand("2".to_string())));
+    assert_eq!("9/9/9/9/9/9/9/9/9 b K".parse::<ShogiPosition>(), Err(FenError::InvalidHand("K".to_string())));
+    // counts that overflow, add up past the limit or are zero are refused
+    for hands in ["99999999999P", "4294967295PP", "0P"].iter() {
+        let sfen = format!("9/9/9/9/9/9/9/9/9 b {} 1", hands);
+        assert_eq!(sfen.parse::<ShogiPosition>(), Err(FenError::InvalidHand(hands.to_string())));
+    }
+    assert_eq!(ShogiPosition::STARTING_SFEN.parse::<ChessPosition>(), Err(FenError::RankCount { ranks: 9, expected: 8 }));
+    let render = ChessRender::default();
+    let svg = render.render_sfen("4k4/9/4+P4/9/9/9/9/9/4K4 b 2P 1").unwrap().to_string();
+    assert!(svg.starts_with(r#"<svg viewBox="-150 0 1200 900""#));
+    assert!(svg.contains(r#"<g transform="rotate(180 450 50)">"#));
+    assert!(svg.contains(r#"<g transform="rotate(0 450 850)">"#));
+    // promoted pieces are written in red
+    let promoted = svg.find('と').unwrap();
+    assert!(svg[..promoted].rfind(&render.theme.piece_red).unwrap() > svg[..promoted].rfind("<path").unwrap());
+    assert!(svg.contains(">\n2\n</text>"));
+}
+
+#[test]
+fn test_heatmap() {
+    let mut position = ChessPosition::empty();
+    position.set_piece(0, 7, Some(ChessPiece::new(PieceKind::Rook, PieceColor::White)));
+    position.set_piece(0, 4, Some(ChessPiece::new(PieceKind::Pawn, PieceColor::Black)));
+    position.set_piece(2, 6, Some(ChessPiece::new(PieceKind::Knight, PieceColor::White)));
+    let heatmap = AttackHeatmap::from_position(&position);
+    // the pawn stops the rook and is attacked itself
+    assert_eq!(heatmap.status(0, 4), SquareStatus::Contested);
+    assert_eq!(heatmap.attacks(0, 3), 0);
+    assert_eq!(heatmap.attacks(0, 5), 2);
+    assert_eq!(heatmap.attacks(1, 5), 1);
+    assert_eq!(heatmap.status(2, 6), SquareStatus::Occupied);
+    assert_eq!(heatmap.status(0, 7), SquareStatus::Contested);
+    assert_eq!(heatmap.status(7, 0), SquareStatus::Safe);
+    assert_eq!(heatmap.max_attacks(), 2);
+    let render = ChessRender::default();
+    let svg = render.render_heatmap(&heatmap).to_string();
+    assert_eq!(svg.matches(&format!(r#"fill="{}""#, render.theme.check_color)).count(), 2);
+    assert!(svg.contains(&format!(r#"fill="{}" fill-opacity="0.7""#, render.theme.heat_color)));
+}
+
+#[test]
+fn test_gallery() {
+    let render = ChessRender::default();
+    let board = render.render_fen("8/8/8/8/8/8/8/8 w - - 0 1").unwrap();
+    let mut gallery = Gallery::new(3);
+    assert!(render.render_gallery(&gallery).is_empty());
+    for i in 0..5 {
+        gallery.push_captioned(board.clone(), format!("No. {}", i + 1));
+    }
+    gallery.push(board.clone());
+    assert_eq!(gallery.len(), 6);
+    assert_eq!(gallery.page_count(), 1);
+    let sheet = render.render_gallery(&gallery)[0].to_string();
+    assert!(sheet.contains(r#"viewBox="0 0 680 508""#));
+    assert_eq!(sheet.matches("<svg").count(), 7);
+    assert!(
+        sheet.contains(r#"height="200" viewBox="0 0 800 800" width="200" x="460" xmlns="http://www.w3.org/2000/svg" y="264""#)
+    );
+    assert!(sheet.contains("No. 5"));
+    gallery.page_limit = Some(4);
+    assert_eq!(gallery.page_count(), 2);
+    let pages = render.render_gallery(&gallery);
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[1].to_string().matches("<svg").count(), 3);
+    assert!(pages[1].to_string().contains(r#"viewBox="0 0 680 264""#));
+}
+
+#[test]
+fn test_render_backends() {
+    let mut render = ChessRender::default();
+    let position = "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1".parse::<ChessPosition>().unwrap();
+    let ascii = render.render_position_with(&position, AsciiBackend::default());
+    assert_eq!(ascii.lines().next(), Some(". . . . k . . ."));
+    assert_eq!(ascii.lines().nth(6), Some(". . . . P . . ."));
+    render.coordinates = CoordinateStyle::Outside;
+    render.orientation = Orientation::Flipped;
+    let plain = render.render_position_with(&position, TerminalBackend::plain());
+    assert_eq!(plain.lines().next(), Some("1 · · · ♔ · · · ·"));
+    assert_eq!(plain.lines().last(), Some("  h g f e d c b a"));
+    let ansi = render.render_position_with(&position, TerminalBackend::default());
+    assert!(ansi.contains("\x1b[48;2;255;207;158m\x1b[38;2;0;0;0m ♚ \x1b[0m"));
+    // terminals have no lines, tours show their steps
+    render.orientation = Orientation::Normal;
+    let tour = KnightTourSolver::new(5, 5).solve().unwrap();
+    let text = render.render_tour_with(&tour, AsciiBackend::default());
+    assert_eq!(text.lines().count(), 6);
+    assert!(text.contains("25"));
+    let shape = BoardShape::from_rows(&["XX.", "XXX"]);
+    assert_eq!(render.render_shape_with(&shape, AsciiBackend::default()), "2 . .\n1 . . .\n  a b c\n");
+    assert_eq!(render.render_shape(&shape).to_string(), render.render_shape_with(&shape, SvgBackend::default()).to_string());
+    // positions draw the same diagram with their indicators, text leaves those out
+    render.show_side_to_move = true;
+    render.show_castling = true;
+    let svg = render.render_position(&position).to_string();
+    assert_eq!(svg, render.render_position_with(&position, SvgBackend::default()).to_string());
+    assert!(svg.contains("<circle"));
+    assert_eq!(render.render_position_with(&position, AsciiBackend::default()).lines().next(), Some("8 . . . . k . . ."));
+}
+
+#[test]
+fn test_html_viewer() {
+    let render = ChessRender::default();
+    let moves: Vec<ChessMove> = ["e2e4", "e7e5"].iter().map(|m| m.parse().unwrap()).collect();
+    let viewer = render.html_moves("Open <game>", &ChessPosition::starting(), &moves).unwrap();
+    assert_eq!(viewer.len(), 3);
+    let html = viewer.to_html();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>Open &lt;game&gt;</title>"));
+    assert!(html.contains("<figcaption>1... e7e5</figcaption>"));
+    assert_eq!(html.matches("<svg").count(), 6);
+    assert_eq!(html.matches(r#"viewBox="-800 -800 800 800""#).count(), 3);
+    // nothing but the svg namespace refers to the network
+    assert_eq!(html.matches("http").count(), html.matches(r#"xmlns="http://www.w3.org/2000/svg""#).count());
+    assert!(!html.contains("src="));
+    let bad: ChessMove = "e4e5".parse().unwrap();
+    assert!(render.html_moves("", &ChessPosition::starting(), &[bad]).is_err());
+    let tour = KnightTourSolver::new(5, 5).solve().unwrap();
+    let viewer = render.html_tour("Tour", &tour);
+    assert_eq!(viewer.len(), 25);
+    assert!(viewer.to_html().contains("<figcaption>Step 25</figcaption>"));
+    let mut viewer = HtmlViewer::new("Empty");
+    assert!(viewer.is_empty());
+    viewer.push(render.render_shape(&BoardShape::rectangle(2, 2)), render.render_shape(&BoardShape::rectangle(2, 2)), "a & b");
+    assert!(viewer.to_html().contains("<figcaption>a &amp; b</figcaption>"));
+    assert_eq!(Orientation::Clockwise.flipped(), Orientation::CounterClockwise);
+}
+
+#[test]
+fn test_compact_output() {
+    let mut render = ChessRender::default();
+    let full = render.render_fen(ChessPosition::STARTING_FEN).unwrap().to_string();
+    render.compact = true;
+    let compact = render.render_fen(ChessPosition::STARTING_FEN).unwrap().to_string();
+    assert!(compact.len() * 3 < full.len() * 2);
+    // one board rectangle, three in the pattern
+    assert_eq!(compact.matches("<rect").count(), 4);
+    assert!(compact.contains(r#"fill="url(#checkerboard-_FFCF9E-_D18A47-100)""#));
+    assert_eq!(compact.matches("<use").count(), 32);
+    assert_eq!(compact.matches(r#"id="piece-classic-"#).count(), 12);
+    assert!(compact.contains(r##"href="#piece-classic-Q-_FFFFFF-_000000""##));
+    // rotated boards swap the pattern colors
+    render.orientation = Orientation::Clockwise;
+    let rotated = render.render_fen(ChessPosition::STARTING_FEN).unwrap().to_string();
+    assert!(rotated.contains("checkerboard-_D18A47-_FFCF9E-100"));
+    render.orientation = Orientation::Normal;
+    render.grid_size = 100.0 / 3.0;
+    let rounded = render.render_fen("8/8/8/8/8/8/8/7K w - - 0 1").unwrap().to_string();
+    // only positions are rounded, the glyph keeps its exact size
+    assert!(rounded.contains(r#"transform="translate(233.33 233.33) scale(0.7407407)""#));
+    assert!(rounded.contains(r#"viewBox="0 0 266.67 266.67""#));
+    let shape = BoardShape::from_rows(&["XX", "X."]);
+    assert_eq!(render.render_shape(&shape).to_string().matches("<rect").count(), 6);
+    // hex boards and animations define the glyphs they refer to
+    let mut hex = HexVariant::Glinski.board();
+    hex.set_piece(0, 0, Some(ChessPiece::new(PieceKind::Queen, PieceColor::Black)));
+    let hex = render.render_hex(&hex).to_string();
+    assert_eq!(hex.matches("<defs").count(), 1);
+    assert!(hex.contains(r#"id="piece-classic-q-"#));
+    let moves = ["e2e4".parse::<ChessMove>().unwrap()];
+    let animation = render.animate_moves(&ChessPosition::starting(), &moves, &AnimationTiming::default()).unwrap().to_string();
+    assert_eq!(animation.matches("<defs").count(), 1);
+    assert_eq!(animation.matches(r#"id="piece-"#).count(), 12);
+    assert_eq!(animation.matches("<use").count(), 32);
+    // boards on a gallery page share one set of definitions
+    render.grid_size = 100.0;
+    render.shared_definitions = true;
+    let mut gallery = Gallery::new(2);
+    gallery.arrow_colors.push("#0000FF".to_string());
+    for fen in [ChessPosition::STARTING_FEN, "4k3/8/8/8/8/8/8/4K2R w K - 0 1", "8/8/8/8/8/8/8/8 w - - 0 1"].iter() {
+        let mut annotations = Annotations::default();
+        annotations.arrow(Arrow::new((4, 6), (4, 4))).arrow(Arrow::new((6, 7), (5, 5)).with_color("#0000FF"));
+        gallery.push(render.render_annotated(&fen.parse().unwrap(), &annotations));
+    }
+    let page = render.render_gallery(&gallery)[0].to_string();
+    assert_eq!(page.matches("<defs").count(), 1);
+    assert_eq!(page.matches("<pattern").count(), 1);
+    assert_eq!(page.matches(r#"id="piece-"#).count(), 12);
+    assert_eq!(page.matches("<marker").count(), 2);
+    assert!(page.find("<defs").unwrap() < page.find("<svg height").unwrap());
+}
@@ -0,0 +1,52 @@
+use rayon::prelude::*;
+
+/// Count all solutions on the rayon thread pool without building them
+///
+/// Diagonals under attack are kept as bitmasks, and every way to place the first two bishops is
+/// counted on its own.
+///
+/// # Panics
+///
+/// If `size` is larger than 64.
+///
+/// # Examples
+///
+/// ```
+/// use deus_nqueens::n_bishops_count_parallel;
+/// assert_eq!(n_bishops_count_parallel(3), 5);
+/// ```
+pub fn n_bishops_count_parallel(size: usize) -> u64 {
+    assert!(size <= 64, "boards are limited to 64 columns");
+    if size < 2 {
+        return 1;
+    }
+    let all = u64::MAX >> (64 - size);
+    (0..size)
+        .into_par_iter()
+        .flat_map_iter(|first| (0..size).map(move |second| (first, second)))
+        .map(|(first, second)| {
+            let bit = 1 << first;
+            let (left, right) = (bit << 1, bit >> 1);
+            let next = 1 << second;
+            if (left | right) & next != 0 {
+                return 0;
+            }
+            count_below(all, size - 2, (left | next) << 1, (right | next) >> 1)
+        })
+        .sum()
+}
+
+/// Solutions of the `rows` left, given the attacked diagonals of the next row
+fn count_below(all: u64, rows: usize, left: u64, right: u64) -> u64 {
+    if rows == 0 {
+        return 1;
+    }
+    let mut count = 0;
+    let mut free = all & !(left | right);
+    while free != 0 {
+        let bit = free & free.wrapping_neg();
+        free ^= bit;
+        count += count_below(all, rows - 1, (left | bit) << 1, (right | bit) >> 1);
+    }
+    count
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::BoardState;
 
 impl Display for NBishopsState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -16,3 +17,15 @@ impl Display for NBishopsState {
         Ok(())
     }
 }
+
+impl BoardState for NBishopsState {
+    fn board_size(&self) -> usize {
+        self.size as usize
+    }
+    fn piece_kind(&self) -> PieceKind {
+        PieceKind::Bishop
+    }
+    fn placed(&self) -> Vec<(isize, isize)> {
+        self.filled.iter().enumerate().map(|(row, &column)| (column, row as isize)).collect()
+    }
+}
@@ -1,9 +1,12 @@
-use std::{
-    fmt::{Display, Formatter},
-    iter::from_generator,
-};
+use crate::search::{backtrack_from, parallel_from, RowSearch};
+use chessman::{AttackHeatmap, PieceKind};
+use rayon::prelude::*;
+use std::fmt::{Display, Formatter};
+mod count;
 mod display;
 
+pub use self::count::n_bishops_count_parallel;
+
 #[derive(Clone, Debug)]
 pub struct NBishopsState {
     size: isize,
@@ -35,30 +38,46 @@ impl NBishopsState {
                 column - row != solution_column - solution_row as isize
         })
     }
+    /// All available moves
+    pub fn available_moves(&self) -> Vec<isize> {
+        (0..self.size).filter(|column| self.valid_at(*column)).collect()
+    }
     pub fn go_walk(&mut self, column: isize) {
         self.filled.push(column);
     }
     pub fn go_back(&mut self) {
         self.filled.pop();
     }
+    /// How many bishops attack every square
+    pub fn heatmap(&self) -> AttackHeatmap {
+        let squares = self.filled.iter().enumerate().map(|(row, &column)| (column, row as isize));
+        AttackHeatmap::uniform(self.size as usize, self.size as usize, PieceKind::Bishop, squares)
+    }
 }
 
 /// O(n × n!) time to find all solutions
 pub fn n_bishops_backtrack(size: usize) -> impl Iterator<Item = NBishopsState> {
-    let mut stack = vec![NBishopsState::new(size)];
-    from_generator(move || {
-        while let Some(mut state) = stack.pop() {
-            if state.full_filled() {
-                yield state;
-                continue;
-            };
-            for row in 0..state.size {
-                if state.valid_at(row) {
-                    state.go_walk(row);
-                    stack.push(state.clone());
-                    state.go_back();
-                }
-            }
-        }
-    })
+    backtrack_from(NBishopsState::new(size))
+}
+
+/// All solutions, searched on the rayon thread pool
+///
+/// Every way to place the first two bishops is the root of a subtree searched on its own.
+pub fn n_bishops_parallel(size: usize) -> impl ParallelIterator<Item = NBishopsState> {
+    parallel_from(NBishopsState::new(size))
+}
+
+impl RowSearch for NBishopsState {
+    fn full_filled(&self) -> bool {
+        NBishopsState::full_filled(self)
+    }
+    fn available_moves(&self) -> Vec<isize> {
+        NBishopsState::available_moves(self)
+    }
+    fn go_walk(&mut self, column: isize) {
+        NBishopsState::go_walk(self, column)
+    }
+    fn go_back(&mut self) {
+        NBishopsState::go_back(self)
+    }
 }
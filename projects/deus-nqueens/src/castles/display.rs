@@ -1,4 +1,5 @@
-use crate::castles::NCastlesState;
+use crate::{castles::NCastlesState, BoardState};
+use chessman::PieceKind;
 use std::fmt::{Display, Formatter};
 
 impl Display for NCastlesState {
@@ -17,3 +18,15 @@ impl Display for NCastlesState {
         Ok(())
     }
 }
+
+impl BoardState for NCastlesState {
+    fn board_size(&self) -> usize {
+        self.size
+    }
+    fn piece_kind(&self) -> PieceKind {
+        PieceKind::Rook
+    }
+    fn placed(&self) -> Vec<(isize, isize)> {
+        self.filled.iter().enumerate().map(|(row, &column)| (column as isize, row as isize)).collect()
+    }
+}
@@ -1,4 +1,5 @@
 mod display;
+use chessman::{AttackHeatmap, PieceKind};
 use itertools::Itertools;
 
 #[derive(Clone, Debug)]
@@ -7,6 +8,14 @@ pub struct NCastlesState {
     filled: Vec<usize>,
 }
 
+impl NCastlesState {
+    /// How many rooks attack every square
+    pub fn heatmap(&self) -> AttackHeatmap {
+        let squares = self.filled.iter().enumerate().map(|(row, &column)| (column as isize, row as isize));
+        AttackHeatmap::uniform(self.size, self.size, PieceKind::Rook, squares)
+    }
+}
+
 /// O(n!) time to find all solutions
 pub fn n_castles_permute(size: usize) -> impl Iterator<Item = NCastlesState> {
     (0..size).permutations(size).map(move |filled| NCastlesState { size, filled })
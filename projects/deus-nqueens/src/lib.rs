@@ -4,9 +4,15 @@
 mod bishops;
 mod castles;
 mod queens;
+mod render;
+mod search;
 
 pub use crate::{
-    bishops::{n_bishops_backtrack, NBishopsState},
+    bishops::{n_bishops_backtrack, n_bishops_count_parallel, n_bishops_parallel, NBishopsState},
     castles::{n_castles_permute, NCastlesState},
-    queens::{n_queens_backtrack, n_queens_modular, n_queens_symmetry, NQueensState},
+    queens::{
+        n_queens_backtrack, n_queens_count, n_queens_count_parallel, n_queens_fundamental, n_queens_min_conflicts,
+        n_queens_modular, n_queens_parallel, n_queens_symmetry, CompletionError, NQueensCompletion, NQueensState, Symmetry,
+    },
+    render::BoardState,
 };
@@ -0,0 +1,164 @@
+use super::*;
+use std::fmt::Formatter;
+
+/// Why fixed queens can not be completed to a solution
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CompletionError {
+    /// A queen stands outside of the board
+    OutOfBoard {
+        /// Row and column of the queen
+        queen: (usize, usize),
+        size: usize,
+    },
+    /// Two fixed queens attack each other
+    Conflict {
+        /// Row and column of the queen in the upper row, or the left one on the same row
+        first: (usize, usize),
+        /// Row and column of the other queen
+        second: (usize, usize),
+    },
+}
+
+impl Display for CompletionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompletionError::OutOfBoard { queen: (row, column), size } => {
+                write!(f, "queen at row {}, column {} is outside of the {}×{} board", row, column, size, size)
+            }
+            CompletionError::Conflict { first, second } => {
+                let line = if first.0 == second.0 {
+                    "row"
+                }
+                else if first.1 == second.1 {
+                    "column"
+                }
+                else {
+                    "diagonal"
+                };
+                write!(
+                    f,
+                    "queens at row {}, column {} and row {}, column {} share a {}",
+                    first.0, first.1, second.0, second.1, line
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CompletionError {}
+
+/// The n-queens completion problem, some queens are fixed and the others are searched
+///
+/// # Examples
+///
+/// ```
+/// use deus_nqueens::NQueensCompletion;
+/// let puzzle = NQueensCompletion::new(8, &[(0, 0)]).unwrap();
+/// assert_eq!(puzzle.count(), 4);
+/// assert!(NQueensCompletion::new(8, &[(0, 0), (3, 3)]).is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct NQueensCompletion {
+    size: usize,
+    /// Column of the fixed queen in every row
+    fixed: Vec<Option<usize>>,
+}
+
+impl NQueensCompletion {
+    /// Fix queens at `(row, column)` on a `size` × `size` board
+    ///
+    /// Fails if a queen is off the board or two fixed queens attack each other.
+    /// A queen listed twice is fixed once.
+    pub fn new(size: usize, queens: &[(usize, usize)]) -> Result<Self, CompletionError> {
+        let mut queens = queens.to_vec();
+        queens.sort_unstable();
+        queens.dedup();
+        if let Some(&queen) = queens.iter().find(|(row, column)| *row >= size || *column >= size) {
+            return Err(CompletionError::OutOfBoard { queen, size });
+        }
+        for (i, &first) in queens.iter().enumerate() {
+            for &second in &queens[i + 1..] {
+                if attacks(first, second) {
+                    return Err(CompletionError::Conflict { first, second });
+                }
+            }
+        }
+        let mut fixed = vec![None; size];
+        for (row, column) in queens {
+            fixed[row] = Some(column);
+        }
+        Ok(Self { size, fixed })
+    }
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    /// The fixed queens as `(row, column)`, from the top
+    pub fn fixed(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.fixed.iter().enumerate().filter_map(|(row, column)| column.map(|column| (row, column)))
+    }
+    /// Whether `column` in `row` is compatible with the fixed queens
+    pub fn allows(&self, row: usize, column: usize) -> bool {
+        match self.fixed.get(row) {
+            Some(Some(fixed)) => *fixed == column,
+            Some(None) => self.fixed().all(|queen| !attacks(queen, (row, column))),
+            None => false,
+        }
+    }
+    /// All solutions containing the fixed queens
+    pub fn solutions(&self) -> impl Iterator<Item = NQueensState> {
+        let puzzle = self.clone();
+        let mut stack = vec![NQueensState::new(self.size)];
+        from_generator(move || {
+            while let Some(mut state) = stack.pop() {
+                if state.full_filled() {
+                    yield state;
+                    continue;
+                };
+                let row = state.filled.len();
+                for column in state.available_moves() {
+                    if puzzle.allows(row, column as usize) {
+                        state.go_walk(column);
+                        stack.push(state.clone());
+                        state.go_back();
+                    }
+                }
+            }
+        })
+    }
+    /// Count the solutions containing the fixed queens, with the bitmasks of [`n_queens_count`]
+    ///
+    /// # Panics
+    ///
+    /// If the board is larger than 64 columns.
+    pub fn count(&self) -> u64 {
+        assert!(self.size <= 64, "boards are limited to 64 columns");
+        if self.size == 0 {
+            return 1;
+        }
+        let allowed: Vec<u64> = (0..self.size)
+            .map(|row| (0..self.size).filter(|&column| self.allows(row, column)).fold(0, |mask, column| mask | 1 << column))
+            .collect();
+        count_allowed(&allowed, 0, 0, 0)
+    }
+}
+
+/// Whether two queens on different squares attack each other
+fn attacks((row1, column1): (usize, usize), (row2, column2): (usize, usize)) -> bool {
+    row1 == row2 || column1 == column2 || row1.abs_diff(row2) == column1.abs_diff(column2)
+}
+
+/// Solutions of the rows below, each row limited to its allowed columns
+fn count_allowed(allowed: &[u64], columns: u64, left: u64, right: u64) -> u64 {
+    let (mask, rest) = match allowed.split_first() {
+        Some(split) => split,
+        None => return 1,
+    };
+    let mut count = 0;
+    let mut free = mask & !(columns | left | right);
+    while free != 0 {
+        let bit = free & free.wrapping_neg();
+        free ^= bit;
+        count += count_allowed(rest, columns | bit, (left | bit) << 1, (right | bit) >> 1);
+    }
+    count
+}
@@ -0,0 +1,79 @@
+use rayon::prelude::*;
+
+/// Count all solutions without building them, OEIS [A000170](https://oeis.org/A000170)
+///
+/// Columns and diagonals under attack are kept as bitmasks, and only the first queens in the
+/// left half of the top row are tried, their mirror images give the other half.
+///
+/// # Panics
+///
+/// If `size` is larger than 64.
+///
+/// # Examples
+///
+/// ```
+/// use deus_nqueens::n_queens_count;
+/// assert_eq!(n_queens_count(8), 92);
+/// ```
+pub fn n_queens_count(size: usize) -> u64 {
+    assert!(size <= 64, "boards are limited to 64 columns");
+    if size == 0 {
+        return 1;
+    }
+    let all = u64::MAX >> (64 - size);
+    (0..size.div_ceil(2))
+        .map(|column| {
+            let bit = 1 << column;
+            mirror_weight(size, column) * count_below(all, bit, bit << 1, bit >> 1)
+        })
+        .sum()
+}
+
+/// Count all solutions on the rayon thread pool, like [`n_queens_count`]
+///
+/// Every way to place the first two queens is counted on its own.
+pub fn n_queens_count_parallel(size: usize) -> u64 {
+    if size < 2 {
+        return n_queens_count(size);
+    }
+    assert!(size <= 64, "boards are limited to 64 columns");
+    let all = u64::MAX >> (64 - size);
+    (0..size.div_ceil(2))
+        .into_par_iter()
+        .flat_map_iter(|first| (0..size).map(move |second| (first, second)))
+        .map(|(first, second)| {
+            let bit = 1 << first;
+            let (columns, left, right) = (bit, bit << 1, bit >> 1);
+            let next = 1 << second;
+            if (columns | left | right) & next != 0 {
+                return 0;
+            }
+            mirror_weight(size, first) * count_below(all, columns | next, (left | next) << 1, (right | next) >> 1)
+        })
+        .sum()
+}
+
+/// A queen left of the middle of the top row stands for its mirror image too
+fn mirror_weight(size: usize, column: usize) -> u64 {
+    if 2 * column + 1 == size {
+        1
+    }
+    else {
+        2
+    }
+}
+
+/// Solutions of the rows left, given the attacked columns and diagonals of the next row
+fn count_below(all: u64, columns: u64, left: u64, right: u64) -> u64 {
+    if columns == all {
+        return 1;
+    }
+    let mut count = 0;
+    let mut free = all & !(columns | left | right);
+    while free != 0 {
+        let bit = free & free.wrapping_neg();
+        free ^= bit;
+        count += count_below(all, columns | bit, (left | bit) << 1, (right | bit) >> 1);
+    }
+    count
+}
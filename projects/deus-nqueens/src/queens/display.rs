@@ -1,4 +1,5 @@
 use super::*;
+use crate::BoardState;
 
 impl Display for NQueensState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -16,3 +17,15 @@ impl Display for NQueensState {
         Ok(())
     }
 }
+
+impl BoardState for NQueensState {
+    fn board_size(&self) -> usize {
+        self.rank as usize
+    }
+    fn piece_kind(&self) -> PieceKind {
+        PieceKind::Queen
+    }
+    fn placed(&self) -> Vec<(isize, isize)> {
+        self.filled.iter().enumerate().map(|(row, &column)| (column, row as isize)).collect()
+    }
+}
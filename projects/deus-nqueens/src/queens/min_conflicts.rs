@@ -0,0 +1,161 @@
+use super::*;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Random tries to place a queen without collisions before leaving it to the repair
+const GREEDY_TRIES: usize = 16;
+
+/// One solution found by min-conflicts local search, different seeds give different solutions
+///
+/// Takes about linear time and memory, boards of millions of queens are solved in seconds.
+///
+/// # Panics
+///
+/// If the board is 2 × 2 or 3 × 3, which have no solution.
+///
+/// # Examples
+///
+/// ```
+/// use deus_nqueens::{n_queens_min_conflicts, BoardState};
+/// let state = n_queens_min_conflicts(1000, 42);
+/// assert_eq!(state, n_queens_min_conflicts(1000, 42));
+/// assert_eq!(state.placed().len(), 1000);
+/// ```
+pub fn n_queens_min_conflicts(size: usize, seed: u64) -> NQueensState {
+    assert!(size != 2 && size != 3, "there is no solution on a {}×{} board", size, size);
+    match NQueensCompletion::new(size, &[]).ok().and_then(|puzzle| puzzle.min_conflicts(seed, usize::MAX)) {
+        Some(state) => state,
+        None => unreachable!("min-conflicts always finds a solution without fixed queens"),
+    }
+}
+
+impl NQueensCompletion {
+    /// One solution containing the fixed queens, found by min-conflicts local search
+    ///
+    /// Every attempt starts from a new random board and gives up after about `8n` swaps,
+    /// returns `None` if all `attempts` fail, which is always the case when there is no completion.
+    pub fn min_conflicts(&self, seed: u64, attempts: usize) -> Option<NQueensState> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut board = MinConflicts::new(self);
+        for _ in 0..attempts {
+            board.shuffle(&mut rng);
+            if board.repair(&mut rng) {
+                return Some(NQueensState {
+                    rank: self.size() as isize,
+                    filled: board.columns.iter().map(|&column| column as isize).collect(),
+                    unused: BTreeSet::default(),
+                });
+            }
+        }
+        None
+    }
+}
+
+/// A queen in every row and column, only the diagonals may collide
+struct MinConflicts {
+    /// Column of the queen in every row
+    columns: Vec<usize>,
+    /// Rows whose queen may be moved
+    free: Vec<usize>,
+    /// Rows of the fixed queens
+    fixed: Vec<usize>,
+    /// Queens on every `row + column` diagonal
+    down: Vec<u32>,
+    /// Queens on every `row - column` diagonal, shifted by `n - 1`
+    up: Vec<u32>,
+    /// Queens beyond the first on every diagonal, zero for a solution
+    collisions: usize,
+}
+
+impl MinConflicts {
+    fn new(puzzle: &NQueensCompletion) -> Self {
+        let n = puzzle.size();
+        let mut columns = vec![None; n];
+        let mut used = vec![false; n];
+        for (row, column) in puzzle.fixed() {
+            columns[row] = Some(column);
+            used[column] = true;
+        }
+        let (fixed, free): (Vec<usize>, Vec<usize>) = (0..n).partition(|&row| columns[row].is_some());
+        let mut unused = (0..n).filter(|&column| !used[column]);
+        let columns = columns.into_iter().map(|column| column.or_else(|| unused.next()).unwrap_or_default()).collect();
+        let diagonals = (2 * n).saturating_sub(1);
+        Self { columns, free, fixed, down: vec![0; diagonals], up: vec![0; diagonals], collisions: 0 }
+    }
+    fn diagonals(&self, row: usize) -> (usize, usize) {
+        let column = self.columns[row];
+        (row + column, row + self.columns.len() - 1 - column)
+    }
+    fn add(&mut self, row: usize) {
+        let (down, up) = self.diagonals(row);
+        self.collisions += (self.down[down] > 0) as usize + (self.up[up] > 0) as usize;
+        self.down[down] += 1;
+        self.up[up] += 1;
+    }
+    fn remove(&mut self, row: usize) {
+        let (down, up) = self.diagonals(row);
+        self.down[down] -= 1;
+        self.up[up] -= 1;
+        self.collisions -= (self.down[down] > 0) as usize + (self.up[up] > 0) as usize;
+    }
+    fn attacked(&self, row: usize) -> bool {
+        let (down, up) = self.diagonals(row);
+        self.down[down] > 1 || self.up[up] > 1
+    }
+    fn swap(&mut self, a: usize, b: usize) {
+        self.remove(a);
+        self.remove(b);
+        self.columns.swap(a, b);
+        self.add(a);
+        self.add(b);
+    }
+    /// A random permutation of the free queens, placed greedily row by row to avoid collisions
+    fn shuffle(&mut self, rng: &mut StdRng) {
+        self.down.iter_mut().for_each(|count| *count = 0);
+        self.up.iter_mut().for_each(|count| *count = 0);
+        self.collisions = 0;
+        self.free.shuffle(rng);
+        for i in 0..self.fixed.len() {
+            self.add(self.fixed[i]);
+        }
+        for i in 0..self.free.len() {
+            let row = self.free[i];
+            for _ in 0..GREEDY_TRIES {
+                let other = self.free[rng.gen_range(i..self.free.len())];
+                self.columns.swap(row, other);
+                let (down, up) = self.diagonals(row);
+                if self.down[down] == 0 && self.up[up] == 0 {
+                    break;
+                }
+            }
+            self.add(row);
+        }
+    }
+    /// Swap attacked queens with random free queens while that reduces the collisions
+    fn repair(&mut self, rng: &mut StdRng) -> bool {
+        let mut budget = 8 * self.free.len() + 1000;
+        while self.collisions > 0 {
+            let attacked: Vec<usize> = self.free.iter().copied().filter(|&row| self.attacked(row)).collect();
+            if attacked.is_empty() {
+                return false;
+            }
+            for row in attacked {
+                while self.attacked(row) {
+                    if budget == 0 {
+                        return false;
+                    }
+                    budget -= 1;
+                    let other = self.free[rng.gen_range(0..self.free.len())];
+                    if other == row {
+                        continue;
+                    }
+                    let before = self.collisions;
+                    self.swap(row, other);
+                    if self.collisions >= before {
+                        self.swap(row, other);
+                    }
+                }
+            }
+        }
+        true
+    }
+}
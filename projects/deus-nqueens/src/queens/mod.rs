@@ -1,10 +1,23 @@
+use crate::search::{backtrack_from, parallel_from, RowSearch};
+use chessman::{AttackHeatmap, PieceKind};
 use num::Integer;
+use rayon::prelude::*;
 use std::{collections::BTreeSet, fmt::Display, iter::from_generator};
 
+mod completion;
+mod count;
 mod display;
+mod min_conflicts;
 mod symmetry;
 
-#[derive(Clone, Debug)]
+pub use self::{
+    completion::{CompletionError, NQueensCompletion},
+    count::{n_queens_count, n_queens_count_parallel},
+    min_conflicts::n_queens_min_conflicts,
+    symmetry::{n_queens_fundamental, Symmetry},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct NQueensState {
     rank: isize,
     filled: Vec<isize>,
@@ -65,24 +78,38 @@ impl NQueensState {
             None => false,
         };
     }
+    /// How many queens attack every square, a solution leaves every queen unattacked
+    pub fn heatmap(&self) -> AttackHeatmap {
+        let squares = self.filled.iter().enumerate().map(|(row, &column)| (column, row as isize));
+        AttackHeatmap::uniform(self.rank as usize, self.rank as usize, PieceKind::Queen, squares)
+    }
 }
 
 /// O(n × n!) time to find all solutions
 pub fn n_queens_backtrack(size: usize) -> impl Iterator<Item = NQueensState> {
-    let mut stack = vec![NQueensState::new(size)];
-    from_generator(move || {
-        while let Some(mut state) = stack.pop() {
-            if state.full_filled() {
-                yield state;
-                continue;
-            };
-            for row in state.available_moves() {
-                state.go_walk(row);
-                stack.push(state.clone());
-                state.go_back();
-            }
-        }
-    })
+    backtrack_from(NQueensState::new(size))
+}
+
+/// All solutions, searched on the rayon thread pool
+///
+/// Every way to place the first two queens is the root of a subtree searched on its own.
+pub fn n_queens_parallel(size: usize) -> impl ParallelIterator<Item = NQueensState> {
+    parallel_from(NQueensState::new(size))
+}
+
+impl RowSearch for NQueensState {
+    fn full_filled(&self) -> bool {
+        NQueensState::full_filled(self)
+    }
+    fn available_moves(&self) -> Vec<isize> {
+        NQueensState::available_moves(self)
+    }
+    fn go_walk(&mut self, column: isize) {
+        NQueensState::go_walk(self, column)
+    }
+    fn go_back(&mut self) {
+        NQueensState::go_back(self)
+    }
 }
 
 /// O(n × n!) time to find all solutions
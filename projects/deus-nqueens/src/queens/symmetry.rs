@@ -41,3 +41,102 @@ impl NQueensState {
         out
     }
 }
+
+/// The eight symmetries of the square, the dihedral group D4
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Symmetry {
+    Identity,
+    /// Turn by 90° clockwise
+    Rotate90,
+    Rotate180,
+    /// Turn by 90° counterclockwise
+    Rotate270,
+    /// Mirror left and right
+    FlipHorizontal,
+    /// Mirror top and bottom
+    FlipVertical,
+    /// Mirror along the diagonal from the top left corner
+    FlipDiagonal,
+    /// Mirror along the diagonal from the top right corner
+    FlipAntiDiagonal,
+}
+
+impl Symmetry {
+    /// All elements of D4
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::FlipDiagonal,
+        Symmetry::FlipAntiDiagonal,
+    ];
+    /// Where the square `(x, y)` of a `size` × `size` board goes
+    pub fn apply(self, size: isize, x: isize, y: isize) -> (isize, isize) {
+        let m = size - 1;
+        match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::Rotate90 => (m - y, x),
+            Symmetry::Rotate180 => (m - x, m - y),
+            Symmetry::Rotate270 => (y, m - x),
+            Symmetry::FlipHorizontal => (m - x, y),
+            Symmetry::FlipVertical => (x, m - y),
+            Symmetry::FlipDiagonal => (y, x),
+            Symmetry::FlipAntiDiagonal => (m - y, m - x),
+        }
+    }
+}
+
+impl NQueensState {
+    /// The board moved by a symmetry of the square
+    ///
+    /// # Panics
+    ///
+    /// If the board is not completely filled, partial boards do not map to partial boards.
+    pub fn transform(&self, symmetry: Symmetry) -> Self {
+        assert!(self.full_filled(), "only complete boards can be transformed");
+        let mut filled = vec![0; self.filled.len()];
+        for (row, &column) in self.filled.iter().enumerate() {
+            let (x, y) = symmetry.apply(self.rank, column, row as isize);
+            filled[y as usize] = x;
+        }
+        Self { rank: self.rank, filled, unused: BTreeSet::new() }
+    }
+    /// The representative of the symmetry class, the transform with the smallest columns row by row
+    ///
+    /// Two solutions are equivalent exactly when they have the same canonical form.
+    /// Partial boards are their own canonical form.
+    pub fn canonical(&self) -> Self {
+        if !self.full_filled() {
+            return self.clone();
+        }
+        Symmetry::ALL
+            .iter()
+            .map(|&symmetry| self.transform(symmetry))
+            .min_by(|a, b| a.filled.cmp(&b.filled))
+            .unwrap_or_else(|| self.clone())
+    }
+    /// The symmetries that leave the board unchanged, a subgroup of D4
+    pub fn stabilizer(&self) -> Vec<Symmetry> {
+        if !self.full_filled() {
+            return vec![Symmetry::Identity];
+        }
+        Symmetry::ALL.iter().copied().filter(|&symmetry| self.transform(symmetry) == *self).collect()
+    }
+    /// Number of distinct boards equivalent to this one
+    pub fn orbit_size(&self) -> usize {
+        Symmetry::ALL.len() / self.stabilizer().len()
+    }
+}
+
+/// Every symmetry class of solutions exactly once, by its canonical form, with the size of the class
+///
+/// The number of classes is OEIS [A002562](https://oeis.org/A002562), 12 for the 8 queens.
+pub fn n_queens_fundamental(size: usize) -> impl Iterator<Item = (NQueensState, usize)> {
+    n_queens_backtrack(size).filter(|state| state.canonical() == *state).map(|state| {
+        let orbit = state.orbit_size();
+        (state, orbit)
+    })
+}
@@ -0,0 +1,66 @@
+use chessman::{BoardShape, ChessPiece, ChessRender, PieceColor, PieceKind, RenderBackend};
+use svg::{node::element::Group, Document};
+
+const QUEEN_RAYS: [(isize, isize); 8] = [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// A placement of non-attacking pieces that can be drawn as a chess board
+pub trait BoardState {
+    /// Side length of the square board
+    fn board_size(&self) -> usize;
+    /// The kind of piece being placed
+    fn piece_kind(&self) -> PieceKind;
+    /// Squares of the placed pieces as `(x, y)`, one for every filled row
+    fn placed(&self) -> Vec<(isize, isize)>;
+    /// Render the board and its pieces, with the lines every piece attacks along if `attacks` is set
+    ///
+    /// In a solution no attack line reaches another piece.
+    fn render(&self, render: &ChessRender, attacks: bool) -> Document {
+        let size = self.board_size();
+        let mut document = render.document(size as f32, size as f32);
+        if render.compact && !render.shared_definitions {
+            document = document.add(render.draw_definitions([(self.piece_kind(), PieceColor::White)]));
+        }
+        document = document.add(render.draw_checkerboard(size as isize, size as isize));
+        document = document.add(render.draw_coordinates(size as isize, size as isize));
+        if attacks {
+            document = document.add(draw_attack_lines(render, size as isize, self.piece_kind(), &self.placed()));
+        }
+        for (x, y) in self.placed() {
+            document = document.add(render.draw_piece(self.piece_kind(), PieceColor::White, x, y));
+        }
+        document
+    }
+    /// Draw the board and its pieces through any backend, e.g. as text for a terminal
+    fn render_with<B: RenderBackend>(&self, render: &ChessRender, backend: B) -> B::Output {
+        let size = self.board_size();
+        let piece = ChessPiece::new(self.piece_kind(), PieceColor::White);
+        let pieces = self.placed().into_iter().map(|(x, y)| (x, y, piece));
+        render.render_board_with(&BoardShape::rectangle(size, size), pieces, backend)
+    }
+}
+
+/// Draw every ray a piece attacks along, up to the edge or the first piece in the way
+fn draw_attack_lines(render: &ChessRender, size: isize, kind: PieceKind, placed: &[(isize, isize)]) -> Group {
+    let rays: &[(isize, isize)] = match kind {
+        PieceKind::Bishop => &QUEEN_RAYS[4..],
+        PieceKind::Rook => &QUEEN_RAYS[..4],
+        _ => &QUEEN_RAYS,
+    };
+    let mut group = Group::new().set("stroke-linecap", "round").set("opacity", 0.5);
+    for &(x, y) in placed {
+        for &(dx, dy) in rays {
+            let (mut tx, mut ty) = (x, y);
+            while (0..size).contains(&(tx + dx)) && (0..size).contains(&(ty + dy)) {
+                tx += dx;
+                ty += dy;
+                if placed.contains(&(tx, ty)) {
+                    break;
+                }
+            }
+            if (tx, ty) != (x, y) {
+                group = group.add(render.draw_path(x, y, tx, ty).set("stroke-width", render.path_width / 2.0));
+            }
+        }
+    }
+    group
+}
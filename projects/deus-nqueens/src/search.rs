@@ -0,0 +1,52 @@
+use rayon::prelude::*;
+use std::iter::from_generator;
+
+/// A board filled one row at a time, searched by backtracking
+pub(crate) trait RowSearch: Clone + Send {
+    fn full_filled(&self) -> bool;
+    /// Columns of the next row a piece can go to
+    fn available_moves(&self) -> Vec<isize>;
+    fn go_walk(&mut self, column: isize);
+    fn go_back(&mut self);
+}
+
+/// All solutions extending a partially filled board
+pub(crate) fn backtrack_from<S: RowSearch>(state: S) -> impl Iterator<Item = S> {
+    let mut stack = vec![state];
+    from_generator(move || {
+        while let Some(mut state) = stack.pop() {
+            if state.full_filled() {
+                yield state;
+                continue;
+            };
+            for row in state.available_moves() {
+                state.go_walk(row);
+                stack.push(state.clone());
+                state.go_back();
+            }
+        }
+    })
+}
+
+/// All solutions extending a partially filled board, searched on the rayon thread pool
+///
+/// Every way to fill the next two rows is the root of a subtree searched on its own.
+pub(crate) fn parallel_from<S: RowSearch + 'static>(state: S) -> impl ParallelIterator<Item = S> {
+    let mut roots = vec![state];
+    for _ in 0..2 {
+        if roots.iter().any(RowSearch::full_filled) {
+            break;
+        }
+        roots = roots
+            .into_iter()
+            .flat_map(|state| {
+                state.available_moves().into_iter().map(move |column| {
+                    let mut next = state.clone();
+                    next.go_walk(column);
+                    next
+                })
+            })
+            .collect();
+    }
+    roots.into_par_iter().flat_map_iter(backtrack_from)
+}
@@ -1,6 +1,10 @@
+use chessman::{AsciiBackend, ChessRender, Gallery, SquareStatus};
 use deus_nqueens::{
-    n_bishops_backtrack, n_castles_permute, n_queens_backtrack, n_queens_modular, n_queens_symmetry, NQueensState,
+    n_bishops_backtrack, n_bishops_count_parallel, n_bishops_parallel, n_castles_permute, n_queens_backtrack, n_queens_count,
+    n_queens_count_parallel, n_queens_fundamental, n_queens_min_conflicts, n_queens_modular, n_queens_parallel,
+    n_queens_symmetry, BoardState, CompletionError, NBishopsState, NQueensCompletion, NQueensState, Symmetry,
 };
+use rayon::prelude::*;
 
 #[test]
 fn test_n_queens_backtrack() {
@@ -66,3 +70,215 @@ fn test_n_bishops_backtrack() {
     }
     println!("{} solutions found", count);
 }
+
+#[test]
+fn test_heatmaps() {
+    for state in n_queens_backtrack(6) {
+        let heatmap = state.heatmap();
+        assert!(heatmap.is_peaceful());
+        assert_eq!(heatmap.max_attacks(), 4);
+    }
+    let mut state = NQueensState::new(4);
+    state.go_walk(0);
+    state.go_walk(1);
+    let heatmap = state.heatmap();
+    assert!(!heatmap.is_peaceful());
+    assert_eq!(heatmap.status(1, 1), SquareStatus::Contested);
+    assert_eq!(heatmap.status(2, 3), SquareStatus::Safe);
+    assert!(n_castles_permute(4).all(|state| state.heatmap().is_peaceful()));
+    assert!(n_bishops_backtrack(4).all(|state| state.heatmap().is_peaceful()));
+}
+
+#[test]
+fn test_render_states() {
+    let render = ChessRender::default();
+    let queens = n_queens_backtrack(5).next().unwrap();
+    assert_eq!(queens.placed().len(), 5);
+    let plain = queens.render(&render, false).to_string();
+    assert_eq!(plain.matches("<rect").count(), 25);
+    assert!(!plain.contains("<line"));
+    let attacks = queens.render(&render, true).to_string();
+    assert!(attacks.contains("<line"));
+    let castles = n_castles_permute(3).next().unwrap();
+    assert_eq!(castles.piece_kind(), chessman::PieceKind::Rook);
+    assert_eq!(castles.render(&render, true).to_string().matches("<line").count(), 8);
+    let mut bishops = NBishopsState::new(4);
+    bishops.go_walk(0);
+    assert_eq!(bishops.render(&render, true).to_string().matches("<line").count(), 1);
+    assert_eq!(bishops.render_with(&render, AsciiBackend::default()), "B . . .\n. . . .\n. . . .\n. . . .\n");
+}
+
+#[test]
+fn test_solution_gallery() {
+    let render = ChessRender::default();
+    let mut gallery = Gallery::new(8);
+    gallery.page_limit = Some(40);
+    for (i, state) in n_queens_backtrack(8).enumerate() {
+        gallery.push_captioned(state.render(&render, false), format!("#{}", i + 1));
+    }
+    assert_eq!(gallery.len(), 92);
+    let pages = render.render_gallery(&gallery);
+    assert_eq!(pages.len(), 3);
+    assert!(pages[2].to_string().contains("#92"));
+}
+
+#[test]
+fn test_solution_viewer() {
+    let solutions: Vec<NQueensState> = n_queens_backtrack(6).collect();
+    let viewer = ChessRender::default().html_viewer("6 queens", solutions.len(), |render, i| {
+        (solutions[i].render(render, true), format!("Solution {}", i + 1))
+    });
+    assert_eq!(viewer.len(), 4);
+    assert!(viewer.to_html().contains("<figcaption>Solution 4</figcaption>"));
+}
+
+#[test]
+fn test_compact_gallery() {
+    let mut render = ChessRender { shared_definitions: true, ..ChessRender::default() };
+    let mut sizes = vec![];
+    for compact in [false, true] {
+        render.compact = compact;
+        let mut gallery = Gallery::new(8);
+        for state in n_queens_backtrack(8) {
+            gallery.push(state.render(&render, false));
+        }
+        let page = render.render_gallery(&gallery)[0].to_string();
+        sizes.push(page.len());
+        if compact {
+            // the pattern and the glyphs are defined once for the page
+            assert_eq!(page.matches("<defs").count(), 1);
+            assert_eq!(page.matches("<pattern").count(), 1);
+            assert_eq!(page.matches(r#"id="piece-"#).count(), 12);
+            assert_eq!(page.matches("<use").count(), 92 * 8);
+        }
+    }
+    assert!(sizes[1] * 3 < sizes[0]);
+    render.shared_definitions = false;
+    let board = n_queens_modular(8).unwrap().render(&render, true).to_string();
+    assert_eq!(board.matches("<defs").count(), 1);
+}
+
+/// OEIS A000170, solutions of the n-queens problem
+const QUEENS_SOLUTIONS: [u64; 19] =
+    [1, 1, 0, 0, 2, 10, 4, 40, 92, 352, 724, 2680, 14200, 73712, 365596, 2279184, 14772512, 95815104, 666090624];
+
+#[test]
+fn test_n_queens_count() {
+    for (size, &count) in QUEENS_SOLUTIONS.iter().enumerate().take(16) {
+        assert_eq!(n_queens_count(size), count, "{size} queens");
+    }
+    assert_eq!(n_queens_count(8), n_queens_backtrack(8).count() as u64);
+}
+
+/// Minutes even in release mode, run with `cargo test --release -- --ignored`
+#[test]
+#[ignore]
+fn test_n_queens_count_large() {
+    for (size, &count) in QUEENS_SOLUTIONS.iter().enumerate().skip(16) {
+        assert_eq!(n_queens_count(size), count, "{size} queens");
+    }
+}
+
+#[test]
+fn test_parallel_search() {
+    for (size, &count) in QUEENS_SOLUTIONS.iter().enumerate().take(14) {
+        assert_eq!(n_queens_count_parallel(size), count, "{size} queens");
+    }
+    let mut solutions: Vec<String> = n_queens_parallel(8).map(|state| state.to_string()).collect();
+    let mut sequential: Vec<String> = n_queens_backtrack(8).map(|state| state.to_string()).collect();
+    solutions.sort();
+    sequential.sort();
+    assert_eq!(solutions, sequential);
+    assert_eq!(n_queens_parallel(1).count(), 1);
+    assert_eq!(n_bishops_parallel(5).count(), n_bishops_backtrack(5).count());
+    for size in 0..7 {
+        assert_eq!(n_bishops_count_parallel(size), n_bishops_backtrack(size).count() as u64, "{size} bishops");
+    }
+}
+
+#[test]
+fn test_symmetry_classes() {
+    // OEIS A002562
+    let classes = [1, 1, 0, 0, 1, 2, 1, 6, 12, 46, 92];
+    for (size, &count) in classes.iter().enumerate() {
+        let fundamental: Vec<(NQueensState, usize)> = n_queens_fundamental(size).collect();
+        assert_eq!(fundamental.len(), count, "{size} queens");
+        let orbits: usize = fundamental.iter().map(|(_, orbit)| orbit).sum();
+        assert_eq!(orbits as u64, n_queens_count(size), "{size} queens");
+    }
+    let solutions: Vec<NQueensState> = n_queens_backtrack(8).collect();
+    for state in &solutions {
+        let canonical = state.canonical();
+        assert_eq!(canonical.canonical(), canonical);
+        assert!(Symmetry::ALL.iter().any(|&symmetry| state.transform(symmetry) == canonical));
+        assert_eq!(state.stabilizer()[0], Symmetry::Identity);
+    }
+    // the one class of 8 queens with a nontrivial stabilizer is symmetric under a half turn
+    let symmetric: Vec<&NQueensState> = solutions.iter().filter(|state| state.stabilizer().len() > 1).collect();
+    assert_eq!(symmetric.len(), 4);
+    assert_eq!(symmetric[0].stabilizer(), vec![Symmetry::Identity, Symmetry::Rotate180]);
+    let four: Vec<(NQueensState, usize)> = n_queens_fundamental(4).collect();
+    assert_eq!(four[0].1, 2);
+    assert_eq!(four[0].0.stabilizer(), vec![Symmetry::Identity, Symmetry::Rotate90, Symmetry::Rotate180, Symmetry::Rotate270]);
+}
+
+#[test]
+fn test_n_queens_completion() {
+    let puzzle = NQueensCompletion::new(8, &[(2, 0), (0, 3)]).unwrap();
+    assert_eq!(puzzle.fixed().collect::<Vec<_>>(), vec![(0, 3), (2, 0)]);
+    let solutions: Vec<NQueensState> = puzzle.solutions().collect();
+    assert_eq!(solutions.len() as u64, puzzle.count());
+    let expected = n_queens_backtrack(8).filter(|state| {
+        let heatmap = state.heatmap();
+        heatmap.pieces().iter().any(|&(x, y, _)| (x, y) == (3, 0)) && heatmap.pieces().iter().any(|&(x, y, _)| (x, y) == (0, 2))
+    });
+    assert_eq!(expected.count(), solutions.len());
+    assert!(!solutions.is_empty());
+    // nothing fixed is the plain problem, a full solution completes only to itself
+    assert_eq!(NQueensCompletion::new(10, &[]).unwrap().count(), 724);
+    let queens = [(0, 1), (1, 3), (2, 0), (3, 2)];
+    assert_eq!(NQueensCompletion::new(4, &queens).unwrap().count(), 1);
+    assert_eq!(NQueensCompletion::new(4, &[(0, 0)]).unwrap().solutions().count(), 0);
+    assert_eq!(NQueensCompletion::new(4, &[(1, 1), (1, 1)]).unwrap().fixed().count(), 1);
+    let error = NQueensCompletion::new(8, &[(5, 1), (2, 4)]).unwrap_err();
+    assert_eq!(error, CompletionError::Conflict { first: (2, 4), second: (5, 1) });
+    assert_eq!(error.to_string(), "queens at row 2, column 4 and row 5, column 1 share a diagonal");
+    let error = NQueensCompletion::new(8, &[(1, 6), (1, 2)]).unwrap_err();
+    assert_eq!(error.to_string(), "queens at row 1, column 2 and row 1, column 6 share a row");
+    assert_eq!(NQueensCompletion::new(8, &[(8, 0)]), Err(CompletionError::OutOfBoard { queen: (8, 0), size: 8 }));
+}
+
+/// Whether no two queens of a full board attack each other, in linear time
+fn is_n_queens_solution(state: &NQueensState) -> bool {
+    let n = state.board_size() as isize;
+    let placed = state.placed();
+    let mut lines = std::collections::HashSet::new();
+    placed.len() == n as usize
+        && placed
+            .iter()
+            .all(|&(x, y)| (0..n).contains(&x) && lines.insert((0, x)) && lines.insert((1, x + y)) && lines.insert((2, x - y)))
+}
+
+#[test]
+fn test_n_queens_min_conflicts() {
+    for n in [0, 1, 4, 5, 8, 50, 1000] {
+        assert!(is_n_queens_solution(&n_queens_min_conflicts(n, 7)));
+    }
+    let large = n_queens_min_conflicts(20000, 1);
+    assert!(is_n_queens_solution(&large));
+    assert_eq!(large, n_queens_min_conflicts(20000, 1));
+    assert_ne!(large, n_queens_min_conflicts(20000, 2));
+    // different seeds reach many of the 92 solutions
+    let distinct: std::collections::HashSet<NQueensState> = (0..200).map(|seed| n_queens_min_conflicts(8, seed)).collect();
+    assert!(distinct.len() > 40);
+    // fixed queens are kept, impossible completions give up
+    let fixed = [(0, 0), (3, 500), (999, 2), (400, 401)];
+    let puzzle = NQueensCompletion::new(1000, &fixed).unwrap();
+    let state = puzzle.min_conflicts(3, 10).unwrap();
+    assert!(is_n_queens_solution(&state));
+    let placed = state.placed();
+    assert!(fixed.iter().all(|&(row, column)| placed[row] == (column as isize, row as isize)));
+    assert_eq!(NQueensCompletion::new(4, &[(0, 0)]).unwrap().min_conflicts(0, 20), None);
+    let unique = NQueensCompletion::new(8, &[(0, 0), (1, 4)]).unwrap();
+    assert_eq!(unique.min_conflicts(0, 1000), unique.solutions().next());
+}